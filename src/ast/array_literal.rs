@@ -0,0 +1,35 @@
+use super::expression::Expression;
+use super::token_node::TokenNode;
+use crate::token::{Span, Token, TokenType};
+
+#[derive(Debug, Clone)]
+pub struct ArrayLiteral {
+    pub token: Token,
+    pub elements: Vec<Expression>,
+}
+
+impl TokenNode for ArrayLiteral {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        match self.elements.last() {
+            Some(element) => self.token.span.to(&element.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
+    fn string(&self) -> String {
+        let elements = self
+            .elements
+            .iter()
+            .map(|exp| exp.string())
+            .collect::<Vec<_>>();
+        format!("[{}]", elements.join(", "))
+    }
+}
@@ -0,0 +1,34 @@
+use super::expression::Expression;
+use super::token_node::TokenNode;
+use crate::token::{Span, Token, TokenType};
+
+// `token` is the assignment operator: `=`, `+=`, `-=`, `*=` or `/=`.
+#[derive(Debug, Clone)]
+pub struct AssignExpression {
+    pub token: Token,
+    pub target: Expression,
+    pub value: Expression,
+}
+
+impl TokenNode for AssignExpression {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.target.span().to(&self.value.span())
+    }
+
+    fn string(&self) -> String {
+        format!(
+            "({} {} {})",
+            self.target.string(),
+            self.token.literal,
+            self.value.string()
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use num_bigint::BigInt;
+
+use crate::token::{Span, Token, TokenType};
+
+use super::token_node::TokenNode;
+
+// An integer literal too large for an `i64`. The parser only produces one
+// when big integers are enabled.
+#[derive(Debug, Clone)]
+pub struct BigIntegerLiteral {
+    pub token: Token,
+    pub value: BigInt,
+}
+
+impl TokenNode for BigIntegerLiteral {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
+    fn string(&self) -> String {
+        self.token.literal.clone()
+    }
+}
@@ -1,6 +1,6 @@
 use super::token_node::TokenNode;
 use super::{statement::Statement, statement_node::StatementNode};
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct BlockStatement {
@@ -17,6 +17,13 @@ impl TokenNode for BlockStatement {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        match self.statements.last() {
+            Some(statement) => self.token.span.to(&statement.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = String::new();
         for statement in self.statements.iter() {
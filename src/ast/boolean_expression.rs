@@ -1,5 +1,5 @@
 use super::{statement_node::StatementNode, token_node::TokenNode};
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct BooleanExpression {
@@ -16,6 +16,10 @@ impl TokenNode for BooleanExpression {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
     fn string(&self) -> String {
         format!("{}", self.value)
     }
@@ -1,6 +1,6 @@
 use super::expression::Expression;
 use super::token_node::TokenNode;
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct CallExpression {
@@ -18,6 +18,14 @@ impl TokenNode for CallExpression {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        let span = self.function.span();
+        match self.arguments.last() {
+            Some(argument) => span.to(&argument.span()),
+            None => span.to(&self.token.span),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = format!("{}(", self.function.string());
         let arguments = self
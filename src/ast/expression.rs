@@ -1,21 +1,37 @@
 use std::path::Prefix;
 
 use super::{
-    boolean_expression::BooleanExpression, call_expression::CallExpression, fn_literal::FnLiteral,
-    identifier::Identifier, if_expression::IfExpression, infix_expression::InfixExpression,
-    integer_literal::IntegerLiteral, prefix_expression::PrefixExpression, token_node::TokenNode,
+    array_literal::ArrayLiteral, assign_expression::AssignExpression,
+    big_integer_literal::BigIntegerLiteral, boolean_expression::BooleanExpression,
+    call_expression::CallExpression, float_literal::FloatLiteral, fn_literal::FnLiteral,
+    for_expression::ForExpression, hash_literal::HashLiteral, identifier::Identifier,
+    if_expression::IfExpression, index_expression::IndexExpression,
+    infix_expression::InfixExpression, integer_literal::IntegerLiteral,
+    loop_control_expression::LoopControlExpression, prefix_expression::PrefixExpression,
+    string_literal::StringLiteral, token_node::TokenNode, while_expression::WhileExpression,
 };
+use crate::token::Span;
 
 #[derive(Debug, Clone)]
 pub enum Expression {
     Identifier(Identifier),
     IntegerLiteral(IntegerLiteral),
+    BigIntegerLiteral(BigIntegerLiteral),
+    FloatLiteral(FloatLiteral),
+    StringLiteral(StringLiteral),
     Boolean(BooleanExpression),
     InfixExpression(InfixExpression),
     PrefixExpression(PrefixExpression),
     IfExpression(IfExpression),
     FnLiteral(FnLiteral),
     CallExpression(Box<CallExpression>),
+    ArrayLiteral(ArrayLiteral),
+    IndexExpression(Box<IndexExpression>),
+    HashLiteral(HashLiteral),
+    AssignExpression(Box<AssignExpression>),
+    WhileExpression(WhileExpression),
+    ForExpression(ForExpression),
+    LoopControl(LoopControlExpression),
 }
 
 impl Expression {
@@ -23,12 +39,45 @@ impl Expression {
         match self {
             Expression::Identifier(identifier) => identifier.string(),
             Expression::IntegerLiteral(integer_literal) => integer_literal.string(),
+            Expression::BigIntegerLiteral(big_integer_literal) => big_integer_literal.string(),
+            Expression::FloatLiteral(float_literal) => float_literal.string(),
+            Expression::StringLiteral(string_literal) => string_literal.string(),
             Expression::Boolean(boolean_expression) => boolean_expression.string(),
             Expression::PrefixExpression(prefix_expression) => prefix_expression.string(),
             Expression::InfixExpression(infix_expression) => infix_expression.string(),
             Expression::IfExpression(if_expression) => if_expression.string(),
             Expression::FnLiteral(fn_literal) => fn_literal.string(),
             Expression::CallExpression(call_expression) => call_expression.string(),
+            Expression::ArrayLiteral(array_literal) => array_literal.string(),
+            Expression::IndexExpression(index_expression) => index_expression.string(),
+            Expression::HashLiteral(hash_literal) => hash_literal.string(),
+            Expression::AssignExpression(assign_expression) => assign_expression.string(),
+            Expression::WhileExpression(while_expression) => while_expression.string(),
+            Expression::ForExpression(for_expression) => for_expression.string(),
+            Expression::LoopControl(loop_control) => loop_control.string(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Identifier(identifier) => identifier.span(),
+            Expression::IntegerLiteral(integer_literal) => integer_literal.span(),
+            Expression::BigIntegerLiteral(big_integer_literal) => big_integer_literal.span(),
+            Expression::FloatLiteral(float_literal) => float_literal.span(),
+            Expression::StringLiteral(string_literal) => string_literal.span(),
+            Expression::Boolean(boolean_expression) => boolean_expression.span(),
+            Expression::PrefixExpression(prefix_expression) => prefix_expression.span(),
+            Expression::InfixExpression(infix_expression) => infix_expression.span(),
+            Expression::IfExpression(if_expression) => if_expression.span(),
+            Expression::FnLiteral(fn_literal) => fn_literal.span(),
+            Expression::CallExpression(call_expression) => call_expression.span(),
+            Expression::ArrayLiteral(array_literal) => array_literal.span(),
+            Expression::IndexExpression(index_expression) => index_expression.span(),
+            Expression::HashLiteral(hash_literal) => hash_literal.span(),
+            Expression::AssignExpression(assign_expression) => assign_expression.span(),
+            Expression::WhileExpression(while_expression) => while_expression.span(),
+            Expression::ForExpression(for_expression) => for_expression.span(),
+            Expression::LoopControl(loop_control) => loop_control.span(),
         }
     }
 }
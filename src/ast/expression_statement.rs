@@ -2,7 +2,7 @@ use super::expression::Expression;
 use super::identifier::Identifier;
 use super::statement_node::StatementNode;
 use super::token_node::TokenNode;
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct ExpressionStatement {
@@ -19,6 +19,13 @@ impl TokenNode for ExpressionStatement {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        match &self.value {
+            Some(value) => value.span(),
+            None => self.token.span.clone(),
+        }
+    }
+
     fn string(&self) -> String {
         if self.value.is_some() {
             return self.value.clone().unwrap().string();
@@ -0,0 +1,27 @@
+use crate::token::{Span, Token, TokenType};
+
+use super::token_node::TokenNode;
+
+#[derive(Debug, Clone)]
+pub struct FloatLiteral {
+    pub token: Token,
+    pub value: f64,
+}
+
+impl TokenNode for FloatLiteral {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
+    fn string(&self) -> String {
+        self.token.literal.clone()
+    }
+}
@@ -1,6 +1,6 @@
 use super::identifier::Identifier;
 use super::{block_statement::BlockStatement, token_node::TokenNode};
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct FnLiteral {
@@ -18,6 +18,13 @@ impl TokenNode for FnLiteral {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        match &self.body {
+            Some(body) => self.token.span.to(&body.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = format!("{} (", self.token_literal());
         let mut params: Vec<String> = vec![];
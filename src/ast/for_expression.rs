@@ -0,0 +1,37 @@
+use crate::token::{Span, Token, TokenType};
+
+use super::{
+    block_statement::BlockStatement, expression::Expression, identifier::Identifier,
+    token_node::TokenNode,
+};
+
+#[derive(Debug, Clone)]
+pub struct ForExpression {
+    pub token: Token,
+    pub variable: Identifier,
+    pub iterable: Box<Expression>,
+    pub body: Box<BlockStatement>,
+}
+
+impl TokenNode for ForExpression {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.token.span.to(&self.body.span())
+    }
+
+    fn string(&self) -> String {
+        format!(
+            "for {} in {} {}",
+            self.variable.string(),
+            self.iterable.string(),
+            self.body.string()
+        )
+    }
+}
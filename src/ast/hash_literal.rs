@@ -0,0 +1,35 @@
+use super::expression::Expression;
+use super::token_node::TokenNode;
+use crate::token::{Span, Token, TokenType};
+
+#[derive(Debug, Clone)]
+pub struct HashLiteral {
+    pub token: Token,
+    pub pairs: Vec<(Expression, Expression)>,
+}
+
+impl TokenNode for HashLiteral {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        match self.pairs.last() {
+            Some((_, value)) => self.token.span.to(&value.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
+    fn string(&self) -> String {
+        let pairs = self
+            .pairs
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key.string(), value.string()))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", pairs.join(", "))
+    }
+}
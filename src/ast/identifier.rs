@@ -1,5 +1,5 @@
 use super::token_node::TokenNode;
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct Identifier {
@@ -16,6 +16,10 @@ impl TokenNode for Identifier {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
     fn string(&self) -> String {
         self.value.clone()
     }
@@ -1,6 +1,9 @@
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
-use super::{block_statement::BlockStatement, expression::Expression, token_node::TokenNode};
+use super::{
+    block_statement::BlockStatement, expression::Expression,
+    expression_statement::ExpressionStatement, statement::Statement, token_node::TokenNode,
+};
 
 #[derive(Debug, Clone)]
 pub struct IfExpression {
@@ -19,6 +22,13 @@ impl TokenNode for IfExpression {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        match &self.alternative {
+            Some(alternative) => self.token.span.to(&alternative.span()),
+            None => self.token.span.to(&self.consequence.span()),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = format!(
             "if {} {}",
@@ -26,11 +36,32 @@ impl TokenNode for IfExpression {
             self.consequence.string()
         );
 
-        if self.alternative.is_some() {
-            let alternative = self.alternative.clone().unwrap().string();
-            s.push_str(format!(" else {}", alternative).as_str());
+        match (self.else_if(), &self.alternative) {
+            (Some(else_if), _) => s.push_str(format!(" else {}", else_if.string()).as_str()),
+            (None, Some(alternative)) => {
+                s.push_str(format!(" else {}", alternative.string()).as_str())
+            }
+            (None, None) => {}
         }
 
         s
     }
 }
+
+impl IfExpression {
+    // The parser desugars `else if` into an alternative block holding only the
+    // nested if expression; this returns that expression.
+    pub fn else_if(&self) -> Option<&IfExpression> {
+        let alternative = self.alternative.as_ref()?;
+        if alternative.statements.len() != 1 {
+            return None;
+        }
+        match &alternative.statements[0] {
+            Statement::ExpressionStatement(ExpressionStatement {
+                value: Some(Expression::IfExpression(if_expression)),
+                ..
+            }) => Some(if_expression),
+            _ => None,
+        }
+    }
+}
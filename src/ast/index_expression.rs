@@ -0,0 +1,28 @@
+use super::expression::Expression;
+use super::token_node::TokenNode;
+use crate::token::{Span, Token, TokenType};
+
+#[derive(Debug, Clone)]
+pub struct IndexExpression {
+    pub token: Token,
+    pub left: Expression,
+    pub index: Expression,
+}
+
+impl TokenNode for IndexExpression {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.left.span().to(&self.index.span())
+    }
+
+    fn string(&self) -> String {
+        format!("({}[{}])", self.left.string(), self.index.string())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 use super::{expression::Expression, token_node::TokenNode};
 
@@ -19,6 +19,17 @@ impl TokenNode for InfixExpression {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        let start = match &self.left {
+            Some(left) => left.span(),
+            None => self.token.span.clone(),
+        };
+        match &self.right {
+            Some(right) => start.to(&right.span()),
+            None => start.to(&self.token.span),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = String::new();
         s.push_str("(");
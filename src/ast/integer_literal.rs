@@ -1,4 +1,4 @@
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 use super::token_node::TokenNode;
 
@@ -17,6 +17,10 @@ impl TokenNode for IntegerLiteral {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
     fn string(&self) -> String {
         self.token.literal.clone()
     }
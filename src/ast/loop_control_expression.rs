@@ -0,0 +1,33 @@
+use crate::token::{Span, Token, TokenType};
+
+use super::token_node::TokenNode;
+
+// `break` or `continue`, told apart by the token type.
+#[derive(Debug, Clone)]
+pub struct LoopControlExpression {
+    pub token: Token,
+}
+
+impl LoopControlExpression {
+    pub fn is_break(&self) -> bool {
+        self.token.token_type == TokenType::BREAK
+    }
+}
+
+impl TokenNode for LoopControlExpression {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.token.span.clone()
+    }
+
+    fn string(&self) -> String {
+        self.token.literal.clone()
+    }
+}
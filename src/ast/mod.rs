@@ -1,15 +1,23 @@
+pub mod array_literal;
+pub mod assign_expression;
+pub mod big_integer_literal;
 pub mod block_statement;
 pub mod boolean_expression;
 pub mod call_expression;
 pub mod expression;
 pub mod expression_node;
 pub mod expression_statement;
+pub mod float_literal;
 pub mod fn_literal;
+pub mod for_expression;
+pub mod hash_literal;
 pub mod identifier;
 pub mod if_expression;
+pub mod index_expression;
 pub mod infix_expression;
 pub mod integer_literal;
 pub mod let_statement;
+pub mod loop_control_expression;
 pub mod node;
 pub mod operators;
 pub mod prefix_expression;
@@ -17,5 +25,7 @@ pub mod program;
 pub mod return_statement;
 pub mod statement;
 pub mod statement_node;
+pub mod string_literal;
 pub mod token_node;
 pub mod trace;
+pub mod while_expression;
@@ -1,11 +1,14 @@
 use super::{
-    block_statement::BlockStatement, boolean_expression::BooleanExpression,
-    call_expression::CallExpression, expression::Expression,
-    expression_statement::ExpressionStatement, fn_literal::FnLiteral, identifier::Identifier,
-    if_expression::IfExpression, infix_expression::InfixExpression,
-    integer_literal::IntegerLiteral, let_statement::LetStatement,
+    array_literal::ArrayLiteral, assign_expression::AssignExpression,
+    big_integer_literal::BigIntegerLiteral, block_statement::BlockStatement,
+    boolean_expression::BooleanExpression, call_expression::CallExpression, expression::Expression,
+    expression_statement::ExpressionStatement, float_literal::FloatLiteral, fn_literal::FnLiteral,
+    for_expression::ForExpression, hash_literal::HashLiteral, identifier::Identifier,
+    if_expression::IfExpression, index_expression::IndexExpression,
+    infix_expression::InfixExpression, integer_literal::IntegerLiteral,
+    let_statement::LetStatement, loop_control_expression::LoopControlExpression,
     prefix_expression::PrefixExpression, program::Program, return_statement::ReturnStatement,
-    statement::Statement,
+    statement::Statement, string_literal::StringLiteral, while_expression::WhileExpression,
 };
 
 pub enum Node {
@@ -21,7 +24,17 @@ pub enum Node {
     IfExpression(IfExpression),
     InfixExpression(InfixExpression),
     IntegerLiteral(IntegerLiteral),
+    BigIntegerLiteral(BigIntegerLiteral),
+    FloatLiteral(FloatLiteral),
     LetStatement(LetStatement),
     PrefixExpression(PrefixExpression),
     ReturnStatement(ReturnStatement),
+    StringLiteral(StringLiteral),
+    ArrayLiteral(ArrayLiteral),
+    IndexExpression(IndexExpression),
+    HashLiteral(HashLiteral),
+    AssignExpression(AssignExpression),
+    WhileExpression(WhileExpression),
+    ForExpression(ForExpression),
+    LoopControl(LoopControlExpression),
 }
@@ -6,12 +6,21 @@ use crate::token::TokenType;
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum Operator {
     LOWEST,
+    ASSIGN,      // =, +=, -=, *= or /=
+    LOGICALOR,   // ||
+    LOGICALAND,  // &&
     EQUALS,      // ==
-    LESSGREATER, // > or <
+    LESSGREATER, // >, <, >= or <=
+    BITOR,       // |
+    BITXOR,      // ^
+    BITAND,      // &
+    SHIFT,       // << or >>
     SUM,         // +
-    PRODUCT,     // *
-    PREFIX,      // -X or !X
+    PRODUCT,     // *, / or %
+    PREFIX,      // -X, !X or ~X
+    POWER,       // **, right associative
     CALL,        // myFunction(X)”
+    INDEX,       // array[index]
 }
 
 impl PartialOrd for Operator {
@@ -25,12 +34,21 @@ impl PartialOrd for Operator {
 fn get_operator_precedence(operator: &Operator) -> i8 {
     match operator {
         Operator::LOWEST => 1,
-        Operator::EQUALS => 2,
-        Operator::LESSGREATER => 3,
-        Operator::SUM => 4,
-        Operator::PRODUCT => 5,
-        Operator::PREFIX => 6,
-        Operator::CALL => 7,
+        Operator::ASSIGN => 2,
+        Operator::LOGICALOR => 3,
+        Operator::LOGICALAND => 4,
+        Operator::EQUALS => 5,
+        Operator::LESSGREATER => 6,
+        Operator::BITOR => 7,
+        Operator::BITXOR => 8,
+        Operator::BITAND => 9,
+        Operator::SHIFT => 10,
+        Operator::SUM => 11,
+        Operator::PRODUCT => 12,
+        Operator::PREFIX => 13,
+        Operator::POWER => 14,
+        Operator::CALL => 15,
+        Operator::INDEX => 16,
     }
 }
 
@@ -38,27 +56,53 @@ impl fmt::Display for Operator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Operator::LOWEST => write!(f, "lowest"),
+            Operator::ASSIGN => write!(f, "assign"),
+            Operator::LOGICALOR => write!(f, "logical or"),
+            Operator::LOGICALAND => write!(f, "logical and"),
             Operator::EQUALS => write!(f, "equals"),
             Operator::LESSGREATER => write!(f, "lesser greater"),
+            Operator::BITOR => write!(f, "bitwise or"),
+            Operator::BITXOR => write!(f, "bitwise xor"),
+            Operator::BITAND => write!(f, "bitwise and"),
+            Operator::SHIFT => write!(f, "shift"),
             Operator::SUM => write!(f, "sum"),
             Operator::PRODUCT => write!(f, "product"),
             Operator::PREFIX => write!(f, "prefix"),
+            Operator::POWER => write!(f, "power"),
             Operator::CALL => write!(f, "call"),
+            Operator::INDEX => write!(f, "index"),
         }
     }
 }
 
 pub fn get_token_type_operator_precedence(token_type: TokenType) -> Operator {
     match token_type {
+        TokenType::ASSIGN => Operator::ASSIGN,
+        TokenType::PlusAssign => Operator::ASSIGN,
+        TokenType::MinusAssign => Operator::ASSIGN,
+        TokenType::AsteriskAssign => Operator::ASSIGN,
+        TokenType::SlashAssign => Operator::ASSIGN,
         TokenType::EQ => Operator::EQUALS,
         TokenType::NotEq => Operator::EQUALS,
         TokenType::LT => Operator::LESSGREATER,
         TokenType::GT => Operator::LESSGREATER,
+        TokenType::LtEq => Operator::LESSGREATER,
+        TokenType::GtEq => Operator::LESSGREATER,
+        TokenType::AND => Operator::LOGICALAND,
+        TokenType::OR => Operator::LOGICALOR,
+        TokenType::PIPE => Operator::BITOR,
+        TokenType::CARET => Operator::BITXOR,
+        TokenType::AMPERSAND => Operator::BITAND,
+        TokenType::ShiftLeft => Operator::SHIFT,
+        TokenType::ShiftRight => Operator::SHIFT,
         TokenType::PLUS => Operator::SUM,
         TokenType::MINUS => Operator::SUM,
         TokenType::SLASH => Operator::PRODUCT,
         TokenType::ASTERISK => Operator::PRODUCT,
+        TokenType::PERCENT => Operator::PRODUCT,
+        TokenType::POWER => Operator::POWER,
         TokenType::LPAREN => Operator::CALL,
+        TokenType::LBRACKET => Operator::INDEX,
         _ => Operator::LOWEST,
     }
 }
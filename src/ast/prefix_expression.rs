@@ -1,4 +1,4 @@
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 use super::{expression::Expression, token_node::TokenNode};
 
@@ -18,6 +18,13 @@ impl TokenNode for PrefixExpression {
         self.token.literal.clone()
     }
 
+    fn span(&self) -> Span {
+        match &self.right {
+            Some(right) => self.token.span.to(&right.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = String::new();
         s.push_str("(");
@@ -1,6 +1,6 @@
 use super::statement::Statement;
 use super::token_node::TokenNode;
-use crate::token::TokenType;
+use crate::token::{Span, TokenType};
 
 pub struct Program {
     pub statements: Vec<Statement>,
@@ -22,6 +22,13 @@ impl TokenNode for Program {
         }
     }
 
+    fn span(&self) -> Span {
+        match (self.statements.first(), self.statements.last()) {
+            (Some(first), Some(last)) => first.span().to(&last.span()),
+            _ => Span::default(),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = String::from("");
         for statement in self.statements.iter() {
@@ -39,7 +46,7 @@ mod tests {
             expression::Expression, identifier::Identifier, let_statement::LetStatement,
             program::Program, statement::Statement, token_node::TokenNode,
         },
-        token::Token,
+        token::{Span, Token},
     };
 
     #[test]
@@ -47,11 +54,13 @@ mod tests {
         let token = Token {
             token_type: TokenType::LET,
             literal: String::from("let"),
+            span: Span::default(),
         };
         let my_var = String::from("myVar");
         let identifier_token = Token {
             token_type: TokenType::IDENT,
             literal: my_var.clone(),
+            span: Span::default(),
         };
         let identifier = Identifier {
             token: identifier_token,
@@ -62,6 +71,7 @@ mod tests {
         let value_token = Token {
             token_type: TokenType::IDENT,
             literal: another_var.clone(),
+            span: Span::default(),
         };
         let value_identifier = Expression::Identifier(Identifier {
             token: value_token,
@@ -2,7 +2,7 @@ use super::expression::Expression;
 use super::identifier::Identifier;
 use super::statement_node::StatementNode;
 use super::token_node::TokenNode;
-use crate::token::{Token, TokenType};
+use crate::token::{Span, Token, TokenType};
 
 #[derive(Debug, Clone)]
 pub struct ReturnStatement {
@@ -19,6 +19,13 @@ impl TokenNode for ReturnStatement {
         String::from("RETURN")
     }
 
+    fn span(&self) -> Span {
+        match &self.value {
+            Some(value) => self.token.span.to(&value.span()),
+            None => self.token.span.clone(),
+        }
+    }
+
     fn string(&self) -> String {
         let mut s = String::new();
         s.push_str(self.token_literal().to_string().as_str());
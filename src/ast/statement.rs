@@ -4,7 +4,7 @@ use super::{
     expression_statement::ExpressionStatement, let_statement::LetStatement,
     return_statement::ReturnStatement,
 };
-use crate::token::TokenType;
+use crate::token::{Span, TokenType};
 
 #[derive(Debug, Clone)]
 pub enum Statement {
@@ -30,6 +30,14 @@ impl TokenNode for Statement {
         }
     }
 
+    fn span(&self) -> Span {
+        match self {
+            Statement::ExpressionStatement(st) => st.span(),
+            Statement::LetStatement(st) => st.span(),
+            Statement::ReturnStatement(st) => st.span(),
+        }
+    }
+
     fn string(&self) -> String {
         match self {
             Statement::ExpressionStatement(st) => st.string(),
@@ -1,7 +1,10 @@
-use crate::token::TokenType;
+use crate::token::{Span, TokenType};
 
 pub trait TokenNode {
     fn token_type(&self) -> TokenType;
     fn token_literal(&self) -> String;
+    // Where the node sits in the source, from its first token to the end of
+    // its last sub-expression.
+    fn span(&self) -> Span;
     fn string(&self) -> String;
 }
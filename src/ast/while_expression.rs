@@ -0,0 +1,28 @@
+use crate::token::{Span, Token, TokenType};
+
+use super::{block_statement::BlockStatement, expression::Expression, token_node::TokenNode};
+
+#[derive(Debug, Clone)]
+pub struct WhileExpression {
+    pub token: Token,
+    pub condition: Box<Expression>,
+    pub body: Box<BlockStatement>,
+}
+
+impl TokenNode for WhileExpression {
+    fn token_type(&self) -> TokenType {
+        self.token.token_type
+    }
+
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+
+    fn span(&self) -> Span {
+        self.token.span.to(&self.body.span())
+    }
+
+    fn string(&self) -> String {
+        format!("while {} {}", self.condition.string(), self.body.string())
+    }
+}
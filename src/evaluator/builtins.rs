@@ -0,0 +1,95 @@
+use super::eval_error::EvalError;
+use crate::object::{builtin::Builtin, object::Object};
+
+// Builtins are consulted only after a lookup in the environment fails, so a
+// script may shadow any of them with its own binding.
+pub fn lookup_builtin(name: &str) -> Option<Object> {
+    let function = match name {
+        "len" => len,
+        "first" => first,
+        "last" => last,
+        "rest" => rest,
+        "push" => push,
+        "puts" => puts,
+        _ => return None,
+    };
+    Some(Object::Builtin(Builtin::new(name, function)))
+}
+
+fn check_arity(arguments: &[Object], expected: usize) -> Result<(), EvalError> {
+    if arguments.len() != expected {
+        return Err(EvalError::ArityMismatch {
+            expected: expected,
+            got: arguments.len(),
+            node: None,
+        });
+    }
+    Ok(())
+}
+
+fn unsupported_argument(function: &str, argument: &Object) -> EvalError {
+    EvalError::UnsupportedArgument {
+        function: String::from(function),
+        object_type: argument.object_type(),
+        node: None,
+    }
+}
+
+fn len(arguments: &[Object]) -> Result<Object, EvalError> {
+    check_arity(arguments, 1)?;
+    let length = match &arguments[0] {
+        Object::String(string) => string.value.chars().count(),
+        Object::Array(array) => array.elements.len(),
+        Object::Hash(hash) => hash.len(),
+        argument => return Err(unsupported_argument("len", argument)),
+    };
+    Ok(Object::new_integer(length as i64))
+}
+
+fn first(arguments: &[Object]) -> Result<Object, EvalError> {
+    check_arity(arguments, 1)?;
+    match &arguments[0] {
+        Object::Array(array) => Ok(array.elements.first().cloned().unwrap_or_else(Object::null)),
+        argument => Err(unsupported_argument("first", argument)),
+    }
+}
+
+fn last(arguments: &[Object]) -> Result<Object, EvalError> {
+    check_arity(arguments, 1)?;
+    match &arguments[0] {
+        Object::Array(array) => Ok(array.elements.last().cloned().unwrap_or_else(Object::null)),
+        argument => Err(unsupported_argument("last", argument)),
+    }
+}
+
+fn rest(arguments: &[Object]) -> Result<Object, EvalError> {
+    check_arity(arguments, 1)?;
+    match &arguments[0] {
+        Object::Array(array) => {
+            if array.elements.is_empty() {
+                return Ok(Object::null());
+            }
+            Ok(Object::new_array(array.elements[1..].to_vec()))
+        }
+        argument => Err(unsupported_argument("rest", argument)),
+    }
+}
+
+fn push(arguments: &[Object]) -> Result<Object, EvalError> {
+    check_arity(arguments, 2)?;
+    match &arguments[0] {
+        Object::Array(array) => {
+            let mut elements = array.elements.clone();
+            elements.push(arguments[1].clone());
+            Ok(Object::new_array(elements))
+        }
+        argument => Err(unsupported_argument("push", argument)),
+    }
+}
+
+fn puts(arguments: &[Object]) -> Result<Object, EvalError> {
+    for argument in arguments.iter() {
+        println!("{}", argument.string());
+    }
+    Ok(Object::null())
+}
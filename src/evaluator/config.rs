@@ -0,0 +1,78 @@
+/// How integer arithmetic behaves when a result does not fit in an `i64`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum OverflowPolicy {
+    /// Report an `EvalError::IntegerOverflow` runtime error.
+    Error,
+    /// Wrap around at the boundary of the type (two's complement).
+    Wrapping,
+    /// Clamp the result to `i64::MIN` or `i64::MAX`.
+    Saturating,
+    /// Promote the result to an arbitrary-precision integer. Results that fit
+    /// in an `i64` again are demoted back to a plain integer.
+    Promote,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::Error
+    }
+}
+
+/// What an index expression evaluates to when the index is out of range.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum IndexPolicy {
+    /// Evaluate to `null`.
+    Null,
+    /// Report an `EvalError::IndexOutOfBounds` runtime error.
+    Error,
+}
+
+impl Default for IndexPolicy {
+    fn default() -> Self {
+        IndexPolicy::Null
+    }
+}
+
+/// How many Monkey function calls may be nested before evaluation fails with
+/// `EvalError::CallDepthExceeded`. Each call level takes several kilobytes of
+/// the Rust stack, so the default keeps recursion within the main thread's
+/// stack even in unoptimized builds. Raise it only on threads with more stack.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub overflow_policy: OverflowPolicy,
+    pub index_policy: IndexPolicy,
+    pub max_call_depth: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            overflow_policy: OverflowPolicy::default(),
+            index_policy: IndexPolicy::default(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config::default()
+    }
+
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Config {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    pub fn with_index_policy(mut self, index_policy: IndexPolicy) -> Config {
+        self.index_policy = index_policy;
+        self
+    }
+
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Config {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+}
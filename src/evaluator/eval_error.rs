@@ -0,0 +1,306 @@
+use std::{error, fmt};
+
+use num_bigint::BigInt;
+
+use crate::{ast::expression::Expression, object::object_type::ObjectType, token::Span};
+
+#[derive(Debug, Clone)]
+pub enum EvalError {
+    TypeMismatch {
+        operator: String,
+        left: ObjectType,
+        right: ObjectType,
+        node: Box<Expression>,
+    },
+    UnknownPrefixOperator {
+        operator: String,
+        right: ObjectType,
+        node: Box<Expression>,
+    },
+    UnknownInfixOperator {
+        operator: String,
+        left: ObjectType,
+        right: ObjectType,
+        node: Box<Expression>,
+    },
+    IdentifierNotFound {
+        name: String,
+        node: Box<Expression>,
+    },
+    UndeclaredAssignment {
+        name: String,
+        node: Box<Expression>,
+    },
+    InvalidAssignmentTarget {
+        node: Box<Expression>,
+    },
+    ArityMismatch {
+        expected: usize,
+        got: usize,
+        node: Option<Box<Expression>>,
+    },
+    UnsupportedArgument {
+        function: String,
+        object_type: ObjectType,
+        node: Option<Box<Expression>>,
+    },
+    NotAFunction {
+        object_type: ObjectType,
+        node: Box<Expression>,
+    },
+    NotIterable {
+        object_type: ObjectType,
+        node: Box<Expression>,
+    },
+    OutsideLoop {
+        keyword: String,
+        node: Box<Expression>,
+    },
+    CallDepthExceeded {
+        limit: usize,
+        node: Box<Expression>,
+    },
+    DivisionByZero {
+        node: Box<Expression>,
+    },
+    NegativeExponent {
+        node: Box<Expression>,
+    },
+    NegativeShift {
+        node: Box<Expression>,
+    },
+    IntegerOverflow {
+        operator: String,
+        node: Box<Expression>,
+    },
+    FloatOverflow {
+        node: Box<Expression>,
+    },
+    IndexOutOfBounds {
+        index: BigInt,
+        length: usize,
+        node: Box<Expression>,
+    },
+    IndexNotSupported {
+        left: ObjectType,
+        index: ObjectType,
+        node: Box<Expression>,
+    },
+    UnusableAsHashKey {
+        object_type: ObjectType,
+        node: Box<Expression>,
+    },
+    MissingOperand {
+        operator: String,
+        node: Box<Expression>,
+    },
+    MissingFunctionBody {
+        node: Box<Expression>,
+    },
+    ConversionError {
+        expected: ObjectType,
+        got: ObjectType,
+        value: Option<String>,
+        node: Option<Box<Expression>>,
+    },
+    HostError {
+        message: String,
+        node: Option<Box<Expression>>,
+    },
+    ParseErrors {
+        errors: Vec<String>,
+    },
+}
+
+impl EvalError {
+    // Errors raised by builtins and host functions do not know which call
+    // produced them; the evaluator attaches the call expression once the
+    // builtin returns.
+    pub fn with_node(self, expression: Expression) -> EvalError {
+        match self {
+            EvalError::ArityMismatch {
+                expected,
+                got,
+                node: None,
+            } => EvalError::ArityMismatch {
+                expected: expected,
+                got: got,
+                node: Some(Box::new(expression)),
+            },
+            EvalError::UnsupportedArgument {
+                function,
+                object_type,
+                node: None,
+            } => EvalError::UnsupportedArgument {
+                function: function,
+                object_type: object_type,
+                node: Some(Box::new(expression)),
+            },
+            EvalError::ConversionError {
+                expected,
+                got,
+                value,
+                node: None,
+            } => EvalError::ConversionError {
+                expected: expected,
+                got: got,
+                value: value,
+                node: Some(Box::new(expression)),
+            },
+            EvalError::HostError {
+                message,
+                node: None,
+            } => EvalError::HostError {
+                message: message,
+                node: Some(Box::new(expression)),
+            },
+            _ => self,
+        }
+    }
+
+    /// An error raised by a host function registered on an `Interpreter`.
+    pub fn host(message: &str) -> EvalError {
+        EvalError::HostError {
+            message: String::from(message),
+            node: None,
+        }
+    }
+
+    pub fn node(&self) -> Option<&Expression> {
+        match self {
+            EvalError::TypeMismatch { node, .. } => Some(node.as_ref()),
+            EvalError::UnknownPrefixOperator { node, .. } => Some(node.as_ref()),
+            EvalError::UnknownInfixOperator { node, .. } => Some(node.as_ref()),
+            EvalError::IdentifierNotFound { node, .. } => Some(node.as_ref()),
+            EvalError::UndeclaredAssignment { node, .. } => Some(node.as_ref()),
+            EvalError::InvalidAssignmentTarget { node } => Some(node.as_ref()),
+            EvalError::ArityMismatch { node, .. } => node.as_deref(),
+            EvalError::UnsupportedArgument { node, .. } => node.as_deref(),
+            EvalError::NotAFunction { node, .. } => Some(node.as_ref()),
+            EvalError::NotIterable { node, .. } => Some(node.as_ref()),
+            EvalError::OutsideLoop { node, .. } => Some(node.as_ref()),
+            EvalError::CallDepthExceeded { node, .. } => Some(node.as_ref()),
+            EvalError::DivisionByZero { node } => Some(node.as_ref()),
+            EvalError::NegativeExponent { node } => Some(node.as_ref()),
+            EvalError::NegativeShift { node } => Some(node.as_ref()),
+            EvalError::IntegerOverflow { node, .. } => Some(node.as_ref()),
+            EvalError::FloatOverflow { node } => Some(node.as_ref()),
+            EvalError::IndexOutOfBounds { node, .. } => Some(node.as_ref()),
+            EvalError::IndexNotSupported { node, .. } => Some(node.as_ref()),
+            EvalError::UnusableAsHashKey { node, .. } => Some(node.as_ref()),
+            EvalError::MissingOperand { node, .. } => Some(node.as_ref()),
+            EvalError::MissingFunctionBody { node } => Some(node.as_ref()),
+            EvalError::ConversionError { node, .. } => node.as_deref(),
+            EvalError::HostError { node, .. } => node.as_deref(),
+            EvalError::ParseErrors { .. } => None,
+        }
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        self.node().map(|node| node.span())
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Parse errors carry their own positions.
+        if let Some(span) = self.span() {
+            write!(f, "{}: ", span)?;
+        }
+        match self {
+            EvalError::TypeMismatch {
+                operator,
+                left,
+                right,
+                ..
+            } => write!(f, "type mismatch: {} {} {}", left, operator, right),
+            EvalError::UnknownPrefixOperator {
+                operator, right, ..
+            } => write!(f, "unknown operator: {}{}", operator, right),
+            EvalError::UnknownInfixOperator {
+                operator,
+                left,
+                right,
+                ..
+            } => write!(f, "unknown operator: {} {} {}", left, operator, right),
+            EvalError::IdentifierNotFound { name, .. } => {
+                write!(f, "identifier not found: {}", name)
+            }
+            EvalError::UndeclaredAssignment { name, .. } => {
+                write!(f, "assignment to undeclared variable: {}", name)
+            }
+            EvalError::InvalidAssignmentTarget { node } => {
+                write!(f, "invalid assignment target: {}", node.string())
+            }
+            EvalError::ArityMismatch { expected, got, .. } => write!(
+                f,
+                "wrong number of arguments: expected {}, got {}",
+                expected, got
+            ),
+            EvalError::UnsupportedArgument {
+                function,
+                object_type,
+                ..
+            } => write!(
+                f,
+                "argument to `{}` not supported, got {}",
+                function, object_type
+            ),
+            EvalError::NotAFunction { object_type, .. } => {
+                write!(f, "not a function: {}", object_type)
+            }
+            EvalError::NotIterable { object_type, .. } => {
+                write!(f, "not iterable: {}", object_type)
+            }
+            EvalError::OutsideLoop { keyword, .. } => write!(f, "{} outside of a loop", keyword),
+            EvalError::CallDepthExceeded { limit, .. } => {
+                write!(f, "maximum call depth of {} exceeded", limit)
+            }
+            EvalError::DivisionByZero { .. } => write!(f, "division by zero"),
+            EvalError::NegativeExponent { .. } => write!(f, "negative exponent"),
+            EvalError::NegativeShift { .. } => write!(f, "negative shift amount"),
+            EvalError::IntegerOverflow { node, .. } => {
+                write!(f, "integer overflow: {}", node.string())
+            }
+            EvalError::FloatOverflow { node } => {
+                write!(f, "float result is not finite: {}", node.string())
+            }
+            EvalError::IndexOutOfBounds { index, length, .. } => {
+                write!(f, "index out of bounds: index {}, length {}", index, length)
+            }
+            EvalError::IndexNotSupported { left, index, .. } => {
+                write!(f, "index operator not supported: {}[{}]", left, index)
+            }
+            EvalError::UnusableAsHashKey { object_type, .. } => {
+                write!(f, "unusable as hash key: {}", object_type)
+            }
+            EvalError::MissingOperand { operator, .. } => {
+                write!(f, "missing operand for operator: {}", operator)
+            }
+            EvalError::MissingFunctionBody { .. } => write!(f, "function literal has no body"),
+            EvalError::ConversionError {
+                expected,
+                got,
+                value: Some(value),
+                ..
+            } => write!(
+                f,
+                "cannot convert argument: {} {} is out of range for {}",
+                got, value, expected
+            ),
+            EvalError::ConversionError { expected, got, .. } => {
+                write!(
+                    f,
+                    "cannot convert argument: expected {}, got {}",
+                    expected, got
+                )
+            }
+            EvalError::HostError { message, .. } => write!(f, "{}", message),
+            EvalError::ParseErrors { errors } => {
+                write!(f, "parser errors:\n\t{}", errors.join("\n\t"))
+            }
+        }
+    }
+}
+
+impl error::Error for EvalError {}
@@ -1,115 +1,513 @@
+use std::{cell::RefCell, rc::Rc};
+
+use num_bigint::BigInt;
+
+use super::builtins::lookup_builtin;
+use super::config::{Config, IndexPolicy, OverflowPolicy};
+use super::eval_error::EvalError;
+use super::evaluate_assign_expression::evaluate_assign_expression;
+use super::evaluate_call_expression::evaluate_call_expression;
 use super::evaluate_infix_expression::evaluate_infix_expression;
 use crate::{
     ast::{
-        expression::Expression, node::Node, program::Program, statement::Statement,
-        token_node::TokenNode,
+        array_literal::ArrayLiteral, expression::Expression, fn_literal::FnLiteral,
+        for_expression::ForExpression, hash_literal::HashLiteral, identifier::Identifier,
+        if_expression::IfExpression, index_expression::IndexExpression,
+        infix_expression::InfixExpression, let_statement::LetStatement, node::Node,
+        prefix_expression::PrefixExpression, return_statement::ReturnStatement,
+        statement::Statement, token_node::TokenNode, while_expression::WhileExpression,
     },
-    lexer::Lexer,
+    interpreter::Interpreter,
     object::bool::Bool,
     object::integer::Integer,
     object::return_value::ReturnValue,
     object::{
+        array::Array,
+        big_integer::BigInteger,
         bool::{FALSE, TRUE},
+        environment::Environment,
+        float::Float,
+        function::Function,
+        hash::Hash,
+        loop_control::LoopControl,
         null::{Null, NULL},
         object::Object,
+        string::Str,
         truthiness_trait::Truthiness,
     },
-    parser::parser::Parser,
 };
 
-pub fn evaluate(input: &str) -> Result<Object, String> {
-    let mut l = Lexer::new(input);
-    let mut p = Parser::new(&mut l);
-    let program: Program = p.parse_program();
-    println!("program: {}", program.string());
-    evaluate_node(Node::Program(program))
+pub fn evaluate(input: &str) -> Result<Object, EvalError> {
+    evaluate_with_config(input, &Config::default())
 }
 
-fn evaluate_node(node: Node) -> Result<Object, String> {
+pub fn evaluate_with_config(input: &str, config: &Config) -> Result<Object, EvalError> {
+    Interpreter::with_config(*config).eval(input)
+}
+
+// Every nested expression passes through `evaluate_node` and
+// `evaluate_expression`, so their arms only dispatch; the work happens in
+// helpers whose locals are on the stack only while they run. This keeps each
+// level of Monkey recursion small, but it is `Config::max_call_depth` that
+// stops deep recursion before it exhausts the Rust stack.
+pub fn evaluate_node(
+    node: Node,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
     match node {
-        Node::Statement(stmt) => match stmt {
-            Statement::ExpressionStatement(expression_statement) => {
-                evaluate_node(Node::Expression(expression_statement.value.unwrap()))
-            }
-            Statement::ReturnStatement(return_statement) => {
-                let value = return_statement.value;
-                if value.is_none() {
-                    return Ok(Object::null());
-                }
-                let return_value = evaluate_node(
-                    Node::Expression(value.unwrap()));
-                if return_value.is_err() {
-                    return return_value
-                }
-                Ok(Object::ReturnValue(Box::from(ReturnValue{
-                    value: return_value.unwrap(),
-                })))
-            }
-            _ => panic!("unexpected statement type"),
-        },
-        Node::Expression(expression) => match expression {
-            Expression::IntegerLiteral(integer) => evaluate_node(Node::IntegerLiteral(integer)),
-            Expression::Boolean(bool_expression) => {
-                evaluate_node(Node::BooleanExpression(bool_expression))
-            }
-            Expression::PrefixExpression(prefix_expression) => {
-                evaluate_node(Node::PrefixExpression(prefix_expression))
-            }
-            Expression::InfixExpression(expression) => {
-                let left = evaluate_node(Node::Expression(*expression.left.unwrap()));
-                let right = evaluate_node(Node::Expression(*expression.right.unwrap()));
-                Ok(evaluate_infix_expression(
-                    expression.operator,
-                    left.unwrap(),
-                    right.unwrap(),
-                ))
-            }
-            Expression::IfExpression(if_expression) => {
-                let condition = evaluate_node(Node::Expression(*if_expression.condition));
-                if condition.is_err() {
-                    return condition;
-                }
-                if condition.unwrap().is_truthy() {
-                    return evaluate_node(Node::BlockStatement(*if_expression.consequence));
-                } else if if_expression.alternative.is_some() {
-                    return evaluate_node(Node::BlockStatement(
-                        *if_expression.alternative.unwrap(),
-                    ));
-                }
-                return Ok(Object::Null(NULL));
-            }
-            _ => panic!("unexpected expression type"),
-        },
-        Node::Program(program) => evaluate_statements(program.statements),
-        Node::IntegerLiteral(integer) => Ok(Object::Integer(Integer {
+        Node::Program(program) => evaluate_program(program.statements, env, config),
+        Node::Statement(statement) => evaluate_statement(statement, env, config),
+        Node::Expression(expression) => evaluate_expression(expression, env, config),
+        Node::BlockStatement(block_statement) => {
+            evaluate_block_statement(block_statement.statements, env, config)
+        }
+        Node::ExpressionStatement(expression_statement) => evaluate_statement(
+            Statement::ExpressionStatement(expression_statement),
+            env,
+            config,
+        ),
+        Node::LetStatement(let_statement) => {
+            evaluate_statement(Statement::LetStatement(let_statement), env, config)
+        }
+        Node::ReturnStatement(return_statement) => {
+            evaluate_statement(Statement::ReturnStatement(return_statement), env, config)
+        }
+        Node::Identifier(identifier) => {
+            evaluate_expression(Expression::Identifier(identifier), env, config)
+        }
+        Node::IntegerLiteral(integer) => {
+            evaluate_expression(Expression::IntegerLiteral(integer), env, config)
+        }
+        Node::BigIntegerLiteral(big_integer) => {
+            evaluate_expression(Expression::BigIntegerLiteral(big_integer), env, config)
+        }
+        Node::FloatLiteral(float) => {
+            evaluate_expression(Expression::FloatLiteral(float), env, config)
+        }
+        Node::StringLiteral(string_literal) => {
+            evaluate_expression(Expression::StringLiteral(string_literal), env, config)
+        }
+        Node::BooleanExpression(bool_expression) => {
+            evaluate_expression(Expression::Boolean(bool_expression), env, config)
+        }
+        Node::FnLiteral(fn_literal) => {
+            evaluate_expression(Expression::FnLiteral(fn_literal), env, config)
+        }
+        Node::CallExpression(call_expression) => {
+            evaluate_call_expression(call_expression, env, config)
+        }
+        Node::ArrayLiteral(array_literal) => evaluate_array_literal(array_literal, env, config),
+        Node::HashLiteral(hash_literal) => evaluate_hash_literal(hash_literal, env, config),
+        Node::IndexExpression(index_expression) => {
+            evaluate_index_node(index_expression, env, config)
+        }
+        Node::PrefixExpression(prefix_expression) => {
+            evaluate_prefix_node(prefix_expression, env, config)
+        }
+        Node::InfixExpression(infix_expression) => {
+            evaluate_infix_node(infix_expression, env, config)
+        }
+        Node::IfExpression(if_expression) => evaluate_if_expression(if_expression, env, config),
+        Node::AssignExpression(assign_expression) => {
+            evaluate_assign_expression(assign_expression, env, config)
+        }
+        Node::WhileExpression(while_expression) => {
+            evaluate_while_expression(while_expression, env, config)
+        }
+        Node::ForExpression(for_expression) => evaluate_for_expression(for_expression, env, config),
+        Node::LoopControl(loop_control) => {
+            evaluate_expression(Expression::LoopControl(loop_control), env, config)
+        }
+    }
+}
+
+pub fn evaluate_expression(
+    expression: Expression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match expression {
+        Expression::Identifier(identifier) => evaluate_identifier(identifier, env),
+        Expression::IntegerLiteral(integer) => Ok(Object::Integer(Integer {
             value: integer.value,
         })),
-        Node::BooleanExpression(bool_expression) => Ok(Object::Bool(Bool {
+        Expression::BigIntegerLiteral(big_integer) => Ok(BigInteger::normalize(big_integer.value)),
+
+        Expression::FloatLiteral(float) => Ok(Object::Float(Float { value: float.value })),
+        Expression::StringLiteral(string_literal) => Ok(Object::String(Str {
+            value: string_literal.value,
+        })),
+        Expression::Boolean(bool_expression) => Ok(Object::Bool(Bool {
             value: bool_expression.value,
         })),
-        Node::BlockStatement(block_statement) => evaluate_statements(block_statement.statements),
-        Node::PrefixExpression(prefix_expression) => {
-            let right = evaluate_node(Node::Expression(*prefix_expression.right.unwrap()));
-            Ok(evaluate_prefix_expression(
-                prefix_expression.operator,
-                right.unwrap(),
-            ))
+        Expression::FnLiteral(fn_literal) => evaluate_fn_literal(fn_literal, env),
+        Expression::CallExpression(call_expression) => {
+            evaluate_call_expression(*call_expression, env, config)
+        }
+        Expression::ArrayLiteral(array_literal) => {
+            evaluate_array_literal(array_literal, env, config)
+        }
+        Expression::HashLiteral(hash_literal) => evaluate_hash_literal(hash_literal, env, config),
+        Expression::IndexExpression(index_expression) => {
+            evaluate_index_node(*index_expression, env, config)
+        }
+        Expression::PrefixExpression(prefix_expression) => {
+            evaluate_prefix_node(prefix_expression, env, config)
+        }
+        Expression::InfixExpression(infix_expression) => {
+            evaluate_infix_node(infix_expression, env, config)
+        }
+        Expression::IfExpression(if_expression) => {
+            evaluate_if_expression(if_expression, env, config)
+        }
+        Expression::AssignExpression(assign_expression) => {
+            evaluate_assign_expression(*assign_expression, env, config)
+        }
+        Expression::WhileExpression(while_expression) => {
+            evaluate_while_expression(while_expression, env, config)
+        }
+        Expression::ForExpression(for_expression) => {
+            evaluate_for_expression(for_expression, env, config)
+        }
+        Expression::LoopControl(loop_control) => Ok(Object::LoopControl(Box::new(LoopControl {
+            expression: loop_control,
+        }))),
+    }
+}
+
+// Evaluates an expression whose result is used as a value. Only statements
+// may raise `break` and `continue`; a signal reaching a let, an operand, an
+// argument or any other value position is reported instead of stored.
+pub fn evaluate_value(
+    expression: Expression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match evaluate_expression(expression, env, config)? {
+        Object::LoopControl(loop_control) => Err(outside_loop_error(*loop_control)),
+        object => Ok(object),
+    }
+}
+
+fn evaluate_statement(
+    statement: Statement,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match statement {
+        Statement::ExpressionStatement(expression_statement) => match expression_statement.value {
+            Some(expression) => evaluate_expression(expression, env, config),
+            None => Ok(Object::null()),
+        },
+        Statement::LetStatement(let_statement) => {
+            evaluate_let_statement(let_statement, env, config)
+        }
+        Statement::ReturnStatement(return_statement) => {
+            evaluate_return_statement(return_statement, env, config)
         }
-        _ => Err(String::from("unexpected node type")),
+    }
+}
+
+fn evaluate_let_statement(
+    let_statement: LetStatement,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let value = match let_statement.value {
+        Some(expression) => evaluate_value(expression, env, config)?,
+        None => Object::null(),
+    };
+    env.borrow_mut().set(let_statement.name.value, value);
+    Ok(Object::null())
+}
+
+fn evaluate_return_statement(
+    return_statement: ReturnStatement,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let value = return_statement.value;
+    if value.is_none() {
+        return Ok(Object::null());
+    }
+    let return_value = evaluate_value(value.unwrap(), env, config)?;
+    Ok(Object::ReturnValue(Box::from(ReturnValue {
+        value: return_value,
+    })))
+}
+
+fn evaluate_fn_literal(
+    fn_literal: FnLiteral,
+    env: &Rc<RefCell<Environment>>,
+) -> Result<Object, EvalError> {
+    match fn_literal.body {
+        Some(body) => Ok(Object::Function(Function {
+            parameters: fn_literal.parameters,
+            body: Rc::new(body),
+            env: Rc::clone(env),
+        })),
+        None => Err(EvalError::MissingFunctionBody {
+            node: Box::new(Expression::FnLiteral(fn_literal)),
+        }),
+    }
+}
+
+fn evaluate_identifier(
+    identifier: Identifier,
+    env: &Rc<RefCell<Environment>>,
+) -> Result<Object, EvalError> {
+    if let Some(object) = env.borrow().get(identifier.value.as_str()) {
+        return Ok(object);
+    }
+    match lookup_builtin(identifier.value.as_str()) {
+        Some(builtin) => Ok(builtin),
+        None => Err(EvalError::IdentifierNotFound {
+            name: identifier.value.clone(),
+            node: Box::new(Expression::Identifier(identifier)),
+        }),
+    }
+}
+
+fn evaluate_array_literal(
+    array_literal: ArrayLiteral,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let mut elements: Vec<Object> = vec![];
+    for element in array_literal.elements.into_iter() {
+        elements.push(evaluate_value(element, env, config)?);
+    }
+    Ok(Object::Array(Array { elements: elements }))
+}
+
+fn evaluate_hash_literal(
+    hash_literal: HashLiteral,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let mut hash = Hash::new();
+    for (key_expression, value_expression) in hash_literal.pairs.into_iter() {
+        let key = evaluate_value(key_expression.clone(), env, config)?;
+        let hash_key = match key.hash_key() {
+            Some(hash_key) => hash_key,
+            None => {
+                return Err(EvalError::UnusableAsHashKey {
+                    object_type: key.object_type(),
+                    node: Box::new(key_expression),
+                })
+            }
+        };
+        let value = evaluate_value(value_expression, env, config)?;
+        hash.insert(hash_key, key, value);
+    }
+    Ok(Object::Hash(hash))
+}
+
+fn evaluate_index_node(
+    index_expression: IndexExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let left = evaluate_value(index_expression.left.clone(), env, config)?;
+    let index = evaluate_value(index_expression.index.clone(), env, config)?;
+    evaluate_index_expression(index_expression, left, index, config)
+}
+
+fn evaluate_prefix_node(
+    prefix_expression: PrefixExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let right = match prefix_expression.right.clone() {
+        Some(right) => evaluate_value(*right, env, config)?,
+        None => {
+            return Err(EvalError::MissingOperand {
+                operator: prefix_expression.operator.clone(),
+                node: Box::new(Expression::PrefixExpression(prefix_expression)),
+            })
+        }
+    };
+    evaluate_prefix_expression(&prefix_expression, right, config)
+}
+
+fn evaluate_infix_node(
+    infix_expression: InfixExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let (left, right) = match (
+        infix_expression.left.clone(),
+        infix_expression.right.clone(),
+    ) {
+        (Some(left), Some(right)) => (left, right),
+        _ => {
+            return Err(EvalError::MissingOperand {
+                operator: infix_expression.operator.clone(),
+                node: Box::new(Expression::InfixExpression(infix_expression)),
+            })
+        }
+    };
+    let left = evaluate_value(*left, env, config)?;
+    let operator = infix_expression.operator.as_str();
+    if operator == AND || operator == OR {
+        return evaluate_logical_expression(operator, left, *right, env, config);
+    }
+    let right = evaluate_value(*right, env, config)?;
+    evaluate_infix_expression(&infix_expression, left, right, config)
+}
+
+fn evaluate_if_expression(
+    if_expression: IfExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let condition = evaluate_value(*if_expression.condition, env, config)?;
+    if condition.is_truthy() {
+        return evaluate_block_statement(if_expression.consequence.statements, env, config);
+    } else if if_expression.alternative.is_some() {
+        return evaluate_block_statement(
+            if_expression.alternative.unwrap().statements,
+            env,
+            config,
+        );
+    }
+    Ok(Object::Null(NULL))
+}
+
+fn evaluate_while_expression(
+    while_expression: WhileExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    loop {
+        let condition = evaluate_value(*while_expression.condition.clone(), env, config)?;
+        if !condition.is_truthy() {
+            break;
+        }
+        let result =
+            evaluate_block_statement(while_expression.body.statements.clone(), env, config)?;
+        match result {
+            Object::ReturnValue(_) => return Ok(result),
+            Object::LoopControl(loop_control) if loop_control.is_break() => break,
+            _ => {}
+        }
+    }
+    Ok(Object::null())
+}
+
+// Arrays yield their elements, hashes their keys in insertion order and
+// strings their characters. Each iteration binds the loop variable in a fresh
+// scope, so closures created in the body capture that iteration's value.
+fn evaluate_for_expression(
+    for_expression: ForExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let iterable = evaluate_value(*for_expression.iterable.clone(), env, config)?;
+    let items = match iterable {
+        Object::Array(array) => array.elements,
+        Object::Hash(hash) => hash.pairs.into_iter().map(|pair| pair.key).collect(),
+        Object::String(string) => string
+            .value
+            .chars()
+            .map(|ch| Object::new_string(ch.to_string().as_str()))
+            .collect(),
+        _ => {
+            return Err(EvalError::NotIterable {
+                object_type: iterable.object_type(),
+                node: for_expression.iterable,
+            })
+        }
+    };
+    for item in items.into_iter() {
+        let mut scope = Environment::new_enclosed(Rc::clone(env));
+        scope.set(for_expression.variable.value.clone(), item);
+        let scope = Rc::new(RefCell::new(scope));
+        let result =
+            evaluate_block_statement(for_expression.body.statements.clone(), &scope, config)?;
+        match result {
+            Object::ReturnValue(_) => return Ok(result),
+            Object::LoopControl(loop_control) if loop_control.is_break() => break,
+            _ => {}
+        }
+    }
+    Ok(Object::null())
+}
+
+// A `break` or `continue` that reaches a function body or the top level was
+// not inside any loop.
+pub fn outside_loop_error(loop_control: LoopControl) -> EvalError {
+    EvalError::OutsideLoop {
+        keyword: loop_control.expression.token_literal(),
+        node: Box::new(Expression::LoopControl(loop_control.expression)),
     }
 }
 
 static BANG: &'static str = "!";
 static MINUS: &'static str = "-";
+static TILDE: &'static str = "~";
+static AND: &'static str = "&&";
+static OR: &'static str = "||";
+
+// `&&` and `||` evaluate the right operand only when the left one does not
+// already decide the result. Both yield a boolean based on truthiness.
+fn evaluate_logical_expression(
+    operator: &str,
+    left: Object,
+    right: Expression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let left = left.is_truthy();
+    if (operator == AND && !left) || (operator == OR && left) {
+        return Ok(Object::new_bool(left));
+    }
+    let right = evaluate_value(right, env, config)?;
+    Ok(Object::new_bool(right.is_truthy()))
+}
 
-fn evaluate_prefix_expression(operator: String, right: Object) -> Object {
+fn evaluate_prefix_expression(
+    prefix_expression: &PrefixExpression,
+    right: Object,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let operator = prefix_expression.operator.as_str();
     if operator == BANG {
-        return evaluate_bang_operator(right);
+        return Ok(evaluate_bang_operator(right));
     }
     if operator == MINUS {
-        return evaluate_minus_operator(right);
+        match right {
+            Object::Integer(integer_object) => {
+                return evaluate_minus_operator(integer_object, prefix_expression, config);
+            }
+            Object::BigInteger(big_integer) => {
+                return Ok(BigInteger::normalize(-big_integer.value));
+            }
+            Object::Float(float_object) => {
+                return Ok(Object::Float(Float {
+                    value: -float_object.value,
+                }));
+            }
+            _ => {}
+        }
+    }
+    if operator == TILDE {
+        match right {
+            Object::Integer(integer_object) => {
+                return Ok(Object::new_integer(!integer_object.value));
+            }
+            Object::BigInteger(big_integer) => {
+                return Ok(BigInteger::normalize(!big_integer.value));
+            }
+            _ => {}
+        }
     }
-    Object::Null(NULL)
+    Err(EvalError::UnknownPrefixOperator {
+        operator: prefix_expression.operator.clone(),
+        right: right.object_type(),
+        node: Box::new(Expression::PrefixExpression(prefix_expression.clone())),
+    })
 }
 
 fn evaluate_bang_operator(right: Object) -> Object {
@@ -125,32 +523,123 @@ fn evaluate_bang_operator(right: Object) -> Object {
     }
 }
 
-fn evaluate_minus_operator(right: Object) -> Object {
-    match right {
-        Object::Integer(integer_object) => Object::Integer(Integer {
-            value: -integer_object.value,
+fn evaluate_minus_operator(
+    right: Integer,
+    prefix_expression: &PrefixExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let value = match config.overflow_policy {
+        OverflowPolicy::Error | OverflowPolicy::Promote => right.value.checked_neg(),
+        OverflowPolicy::Wrapping => Some(right.value.wrapping_neg()),
+        OverflowPolicy::Saturating => Some(right.value.saturating_neg()),
+    };
+    match value {
+        Some(value) => Ok(Object::Integer(Integer { value: value })),
+        None if config.overflow_policy == OverflowPolicy::Promote => {
+            Ok(BigInteger::normalize(-BigInt::from(right.value)))
+        }
+        None => Err(EvalError::IntegerOverflow {
+            operator: prefix_expression.operator.clone(),
+            node: Box::new(Expression::PrefixExpression(prefix_expression.clone())),
         }),
-        _ => Object::Null(NULL),
     }
 }
 
-fn evaluate_statements(statements: Vec<Statement>) -> Result<Object, String> {
+// Evaluates top-level statements, unwrapping the first `ReturnValue` so that a
+// `return` at program level yields its value and stops evaluation.
+fn evaluate_program(
+    statements: Vec<Statement>,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
     let mut result = Object::Null(Null {});
-    for statement in statements.iter() {
-        match evaluate_node(Node::Statement((*statement).clone())) {
-            Ok(object) => {
-                match object {
-                    Object::ReturnValue(return_value) => {
-                        return Ok(return_value.value);
-                    },
-                    _ => {},
-                }
-                result = object;
+    for statement in statements.into_iter() {
+        let object = evaluate_statement(statement, env, config)?;
+        match object {
+            Object::ReturnValue(return_value) => {
+                return Ok(return_value.value);
             }
-            Err(e) => {
-                return Err(e);
+            Object::LoopControl(loop_control) => {
+                return Err(outside_loop_error(*loop_control));
             }
+            _ => {}
         }
+        result = object;
+    }
+    Ok(result)
+}
+
+// Evaluates the statements of a block, stopping at the first `ReturnValue` but
+// leaving it wrapped so it keeps propagating through enclosing blocks until it
+// reaches the program or a function call boundary.
+pub fn evaluate_block_statement(
+    statements: Vec<Statement>,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let mut result = Object::Null(Null {});
+    for statement in statements.into_iter() {
+        result = evaluate_statement(statement, env, config)?;
+        if result.is_return_value() || result.is_loop_control() {
+            return Ok(result);
+        }
+    }
+    Ok(result)
+}
+
+fn evaluate_index_expression(
+    index_expression: IndexExpression,
+    left: Object,
+    index: Object,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match (&left, &index) {
+        (Object::Array(array), Object::Integer(integer)) => {
+            let length = array.elements.len();
+            if integer.value >= 0 && (integer.value as usize) < length {
+                return Ok(array.elements[integer.value as usize].clone());
+            }
+            index_out_of_bounds(
+                index_expression,
+                BigInt::from(integer.value),
+                length,
+                config,
+            )
+        }
+        // Promoted indices do not fit in an `i64`, so no array is that long.
+        (Object::Array(array), Object::BigInteger(big_integer)) => index_out_of_bounds(
+            index_expression,
+            big_integer.value.clone(),
+            array.elements.len(),
+            config,
+        ),
+        (Object::Hash(hash), _) => match index.hash_key() {
+            Some(hash_key) => Ok(hash.get(&hash_key).cloned().unwrap_or_else(Object::null)),
+            None => Err(EvalError::UnusableAsHashKey {
+                object_type: index.object_type(),
+                node: Box::new(index_expression.index),
+            }),
+        },
+        _ => Err(EvalError::IndexNotSupported {
+            left: left.object_type(),
+            index: index.object_type(),
+            node: Box::new(Expression::IndexExpression(Box::new(index_expression))),
+        }),
+    }
+}
+
+fn index_out_of_bounds(
+    index_expression: IndexExpression,
+    index: BigInt,
+    length: usize,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match config.index_policy {
+        IndexPolicy::Null => Ok(Object::null()),
+        IndexPolicy::Error => Err(EvalError::IndexOutOfBounds {
+            index: index,
+            length: length,
+            node: Box::new(Expression::IndexExpression(Box::new(index_expression))),
+        }),
     }
-    return Ok(result);
 }
@@ -0,0 +1,149 @@
+use std::{cell::RefCell, rc::Rc};
+
+use num_bigint::BigInt;
+
+use super::{
+    config::Config, eval_error::EvalError, evaluate::evaluate_value,
+    evaluate_infix_expression::evaluate_infix_expression,
+};
+use crate::{
+    ast::{
+        assign_expression::AssignExpression, expression::Expression, identifier::Identifier,
+        index_expression::IndexExpression, infix_expression::InfixExpression,
+    },
+    object::{environment::Environment, object::Object},
+};
+
+// Assigning to `a[i][j]` replaces the element inside the collection bound to
+// `a` in place. Collections are values, so other bindings holding a copy of
+// the old collection do not see the change.
+pub fn evaluate_assign_expression(
+    assign_expression: AssignExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let (identifier, index_expressions) = assignment_path(&assign_expression.target)?;
+    let mut path = vec![];
+    for index_expression in index_expressions.into_iter() {
+        let index = evaluate_value(index_expression.index.clone(), env, config)?;
+        path.push((index_expression, index));
+    }
+    let value = evaluate_value(assign_expression.value.clone(), env, config)?;
+
+    let assigned = env
+        .borrow_mut()
+        .update(identifier.value.as_str(), |current| {
+            assign_path(current, &path, value, &assign_expression, config)
+        });
+    match assigned {
+        Some(assigned) => assigned,
+        None => Err(EvalError::UndeclaredAssignment {
+            name: identifier.value.clone(),
+            node: Box::new(Expression::Identifier(identifier)),
+        }),
+    }
+}
+
+// Splits `a[i][j]` into the binding `a` and the index expressions `[i]` and
+// `[j]`, outermost container first.
+fn assignment_path(target: &Expression) -> Result<(Identifier, Vec<IndexExpression>), EvalError> {
+    match target {
+        Expression::Identifier(identifier) => Ok((identifier.clone(), vec![])),
+        Expression::IndexExpression(index_expression) => {
+            let (identifier, mut index_expressions) = assignment_path(&index_expression.left)?;
+            index_expressions.push(*index_expression.clone());
+            Ok((identifier, index_expressions))
+        }
+        _ => Err(EvalError::InvalidAssignmentTarget {
+            node: Box::new(target.clone()),
+        }),
+    }
+}
+
+// Walks `path` down from `current` and replaces the element it ends at,
+// returning the value that was assigned. Nothing changes when it fails.
+fn assign_path(
+    current: &mut Object,
+    path: &[(IndexExpression, Object)],
+    value: Object,
+    assign_expression: &AssignExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let ((index_expression, index), rest) = match path.split_first() {
+        Some(first) => first,
+        None => {
+            let assigned = combine(current, value, assign_expression, config)?;
+            *current = assigned.clone();
+            return Ok(assigned);
+        }
+    };
+    match (current, index) {
+        (Object::Array(array), Object::Integer(integer)) => {
+            let length = array.elements.len();
+            if integer.value < 0 || integer.value as usize >= length {
+                return Err(EvalError::IndexOutOfBounds {
+                    index: BigInt::from(integer.value),
+                    length: length,
+                    node: Box::new(Expression::IndexExpression(Box::new(
+                        index_expression.clone(),
+                    ))),
+                });
+            }
+            let element = &mut array.elements[integer.value as usize];
+            assign_path(element, rest, value, assign_expression, config)
+        }
+        (Object::Array(array), Object::BigInteger(big_integer)) => {
+            Err(EvalError::IndexOutOfBounds {
+                index: big_integer.value.clone(),
+                length: array.elements.len(),
+                node: Box::new(Expression::IndexExpression(Box::new(
+                    index_expression.clone(),
+                ))),
+            })
+        }
+        (Object::Hash(hash), _) => match index.hash_key() {
+            Some(hash_key) => match hash.get_mut(&hash_key) {
+                Some(element) => assign_path(element, rest, value, assign_expression, config),
+                None => {
+                    let mut element = Object::null();
+                    let assigned =
+                        assign_path(&mut element, rest, value, assign_expression, config)?;
+                    hash.insert(hash_key, index.clone(), element);
+                    Ok(assigned)
+                }
+            },
+            None => Err(EvalError::UnusableAsHashKey {
+                object_type: index.object_type(),
+                node: Box::new(index_expression.index.clone()),
+            }),
+        },
+        (current, _) => Err(EvalError::IndexNotSupported {
+            left: current.object_type(),
+            index: index.object_type(),
+            node: Box::new(Expression::IndexExpression(Box::new(
+                index_expression.clone(),
+            ))),
+        }),
+    }
+}
+
+// `x += y` evaluates as `x + y`, reporting errors against that expression.
+// Plain `=` does not read the current value, so it is not copied.
+fn combine(
+    current: &Object,
+    value: Object,
+    assign_expression: &AssignExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let operator = assign_expression.token.literal.trim_end_matches('=');
+    if operator.is_empty() {
+        return Ok(value);
+    }
+    let infix_expression = InfixExpression {
+        token: assign_expression.token.clone(),
+        operator: String::from(operator),
+        left: Some(Box::new(assign_expression.target.clone())),
+        right: Some(Box::new(assign_expression.value.clone())),
+    };
+    evaluate_infix_expression(&infix_expression, current.clone(), value, config)
+}
@@ -0,0 +1,108 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use super::{
+    config::Config,
+    eval_error::EvalError,
+    evaluate::{evaluate_block_statement, evaluate_value, outside_loop_error},
+};
+use crate::{
+    ast::{call_expression::CallExpression, expression::Expression},
+    object::{environment::Environment, function::Function, object::Object},
+};
+
+thread_local! {
+    // Number of Monkey function calls currently being evaluated on this thread.
+    static CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn evaluate_call_expression(
+    call_expression: CallExpression,
+    env: &Rc<RefCell<Environment>>,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let function = evaluate_value(call_expression.function.clone(), env, config)?;
+    let mut arguments: Vec<Object> = vec![];
+    for argument in call_expression.arguments.iter() {
+        arguments.push(evaluate_value(argument.clone(), env, config)?);
+    }
+    apply_function(function, arguments, &call_expression, config)
+}
+
+pub fn apply_function(
+    function: Object,
+    arguments: Vec<Object>,
+    call_expression: &CallExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    match function {
+        Object::Function(function) => call_function(function, arguments, call_expression, config),
+        Object::Builtin(builtin) => (builtin.function)(&arguments).map_err(|e| {
+            e.with_node(Expression::CallExpression(Box::new(
+                call_expression.clone(),
+            )))
+        }),
+        _ => Err(EvalError::NotAFunction {
+            object_type: function.object_type(),
+            node: Box::new(Expression::CallExpression(Box::new(
+                call_expression.clone(),
+            ))),
+        }),
+    }
+}
+
+// Each nested call recurses on the Rust stack, so the depth is counted and
+// evaluation fails once it passes `Config::max_call_depth` instead of
+// overflowing the stack.
+fn call_function(
+    function: Function,
+    arguments: Vec<Object>,
+    call_expression: &CallExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    if function.parameters.len() != arguments.len() {
+        return Err(EvalError::ArityMismatch {
+            expected: function.parameters.len(),
+            got: arguments.len(),
+            node: Some(Box::new(Expression::CallExpression(Box::new(
+                call_expression.clone(),
+            )))),
+        });
+    }
+    let depth = CALL_DEPTH.with(|call_depth| call_depth.get());
+    if depth >= config.max_call_depth {
+        return Err(EvalError::CallDepthExceeded {
+            limit: config.max_call_depth,
+            node: Box::new(Expression::CallExpression(Box::new(
+                call_expression.clone(),
+            ))),
+        });
+    }
+    CALL_DEPTH.with(|call_depth| call_depth.set(depth + 1));
+    defer! {
+        CALL_DEPTH.with(|call_depth| call_depth.set(depth));
+    }
+    let env = extend_function_env(&function, arguments);
+    let result = evaluate_block_statement(function.body.statements.clone(), &env, config)?;
+    match result {
+        Object::LoopControl(loop_control) => Err(outside_loop_error(*loop_control)),
+        _ => Ok(unwrap_return_value(result)),
+    }
+}
+
+fn extend_function_env(function: &Function, arguments: Vec<Object>) -> Rc<RefCell<Environment>> {
+    let mut env = Environment::new_enclosed(Rc::clone(&function.env));
+    for (parameter, argument) in function.parameters.iter().zip(arguments) {
+        env.set(parameter.value.clone(), argument);
+    }
+    Rc::new(RefCell::new(env))
+}
+
+fn unwrap_return_value(object: Object) -> Object {
+    match object {
+        Object::ReturnValue(return_value) => return_value.value,
+        _ => object,
+    }
+}
@@ -1,58 +1,506 @@
-use crate::object::{bool::Bool, integer::Integer, null::NULL, object::Object};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
-pub fn evaluate_infix_expression(operator: String, left: Object, right: Object) -> Object {
-    match operator.as_str() {
+use super::{
+    config::{Config, OverflowPolicy},
+    eval_error::EvalError,
+};
+use crate::{
+    ast::{expression::Expression, infix_expression::InfixExpression},
+    object::{
+        big_integer::BigInteger, bool::Bool, float::Float, integer::Integer, object::Object,
+        string::Str,
+    },
+};
+
+pub fn evaluate_infix_expression(
+    infix_expression: &InfixExpression,
+    left: Object,
+    right: Object,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    // Mixed numeric operands are promoted to the wider representation,
+    // including for equality, so `1 == 1.0` holds.
+    match (&left, &right) {
+        (Object::Float(_), _) | (_, Object::Float(_)) => {
+            if let (Some(left_value), Some(right_value)) = (to_f64(&left), to_f64(&right)) {
+                return evaluate_float_infix_expression(
+                    left_value,
+                    right_value,
+                    &left,
+                    &right,
+                    infix_expression,
+                );
+            }
+        }
+        (Object::BigInteger(_), _) | (_, Object::BigInteger(_)) => {
+            if let (Some(left_value), Some(right_value)) = (to_big_int(&left), to_big_int(&right)) {
+                return evaluate_big_integer_infix_expression(
+                    left_value,
+                    right_value,
+                    &left,
+                    &right,
+                    infix_expression,
+                );
+            }
+        }
+        _ => {}
+    }
+    match infix_expression.operator.as_str() {
         "==" => {
-            return Object::Bool(Bool {
+            return Ok(Object::Bool(Bool {
                 value: left == right,
-            });
+            }));
         }
         "!=" => {
-            return Object::Bool(Bool {
+            return Ok(Object::Bool(Bool {
                 value: left != right,
-            });
+            }));
         }
         _ => {}
     }
-    match left {
-        Object::Integer(left_int) => match right {
-            Object::Integer(right_int) => {
-                return evaluate_integer_infix_expression(left_int, right_int, operator);
+    match (&left, &right) {
+        (Object::Integer(left_int), Object::Integer(right_int)) => {
+            evaluate_integer_infix_expression(*left_int, *right_int, infix_expression, config)
+        }
+        (Object::String(left_string), Object::String(right_string)) => {
+            evaluate_string_infix_expression(left_string, right_string, infix_expression)
+        }
+        _ => {
+            if left.object_type() != right.object_type() {
+                return Err(EvalError::TypeMismatch {
+                    operator: infix_expression.operator.clone(),
+                    left: left.object_type(),
+                    right: right.object_type(),
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
             }
-            _ => {}
-        },
-        _ => {}
+            Err(unknown_infix_operator(infix_expression, &left, &right))
+        }
     }
-
-    Object::Null(NULL)
 }
 
-fn evaluate_integer_infix_expression(left: Integer, right: Integer, operator: String) -> Object {
-    if operator == "+" {
-        return Object::Integer(Integer {
-            value: left.value + right.value,
-        });
+fn evaluate_integer_infix_expression(
+    left: Integer,
+    right: Integer,
+    infix_expression: &InfixExpression,
+    config: &Config,
+) -> Result<Object, EvalError> {
+    let operator = infix_expression.operator.as_str();
+    let policy = config.overflow_policy;
+    let value = if operator == "+" {
+        checked_arithmetic(
+            policy,
+            left.value,
+            right.value,
+            i64::checked_add,
+            i64::wrapping_add,
+            i64::saturating_add,
+        )
     } else if operator == "-" {
-        return Object::Integer(Integer {
-            value: left.value - right.value,
-        });
+        checked_arithmetic(
+            policy,
+            left.value,
+            right.value,
+            i64::checked_sub,
+            i64::wrapping_sub,
+            i64::saturating_sub,
+        )
     } else if operator == "*" {
-        return Object::Integer(Integer {
-            value: left.value * right.value,
-        });
+        checked_arithmetic(
+            policy,
+            left.value,
+            right.value,
+            i64::checked_mul,
+            i64::wrapping_mul,
+            i64::saturating_mul,
+        )
     } else if operator == "/" {
-        return Object::Integer(Integer {
-            value: left.value / right.value,
+        if right.value == 0 {
+            return Err(EvalError::DivisionByZero {
+                node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+            });
+        }
+        checked_arithmetic(
+            policy,
+            left.value,
+            right.value,
+            i64::checked_div,
+            i64::wrapping_div,
+            i64::saturating_div,
+        )
+    } else if operator == "%" {
+        if right.value == 0 {
+            return Err(EvalError::DivisionByZero {
+                node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+            });
+        }
+        // The remainder always fits; only `i64::MIN % -1` overflows the
+        // intermediate quotient, and its remainder is 0.
+        Some(left.value.wrapping_rem(right.value))
+    } else if operator == "**" {
+        if right.value < 0 {
+            return Err(EvalError::NegativeExponent {
+                node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+            });
+        }
+        checked_arithmetic(
+            policy,
+            left.value,
+            right.value,
+            checked_pow,
+            wrapping_pow,
+            saturating_pow,
+        )
+    } else if operator == "<<" || operator == ">>" {
+        if right.value < 0 {
+            return Err(EvalError::NegativeShift {
+                node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+            });
+        }
+        if operator == ">>" {
+            Some(left.value >> right.value.min(63))
+        } else {
+            checked_arithmetic(
+                policy,
+                left.value,
+                right.value,
+                checked_shl,
+                wrapping_shl,
+                saturating_shl,
+            )
+        }
+    } else if operator == "&" {
+        Some(left.value & right.value)
+    } else if operator == "|" {
+        Some(left.value | right.value)
+    } else if operator == "^" {
+        Some(left.value ^ right.value)
+    } else {
+        return evaluate_integer_comparison(left, right, infix_expression);
+    };
+
+    match value {
+        Some(value) => Ok(Object::Integer(Integer { value: value })),
+        None if policy == OverflowPolicy::Promote => evaluate_big_integer_infix_expression(
+            BigInt::from(left.value),
+            BigInt::from(right.value),
+            &Object::Integer(left),
+            &Object::Integer(right),
+            infix_expression,
+        ),
+        None => Err(integer_overflow(infix_expression)),
+    }
+}
+
+// Returns `None` only when the operation overflows under `OverflowPolicy::Error`
+// or `OverflowPolicy::Promote`.
+fn checked_arithmetic(
+    policy: OverflowPolicy,
+    left: i64,
+    right: i64,
+    checked: fn(i64, i64) -> Option<i64>,
+    wrapping: fn(i64, i64) -> i64,
+    saturating: fn(i64, i64) -> i64,
+) -> Option<i64> {
+    match policy {
+        OverflowPolicy::Error | OverflowPolicy::Promote => checked(left, right),
+        OverflowPolicy::Wrapping => Some(wrapping(left, right)),
+        OverflowPolicy::Saturating => Some(saturating(left, right)),
+    }
+}
+
+// Exponentiation by squaring, with `multiply` deciding what happens on
+// overflow. `exponent` must not be negative.
+fn integer_pow(base: i64, exponent: i64, multiply: fn(i64, i64) -> Option<i64>) -> Option<i64> {
+    let mut result: i64 = 1;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(base, base)?;
+        }
+    }
+    Some(result)
+}
+
+fn checked_pow(base: i64, exponent: i64) -> Option<i64> {
+    integer_pow(base, exponent, i64::checked_mul)
+}
+
+fn wrapping_pow(base: i64, exponent: i64) -> i64 {
+    integer_pow(base, exponent, |a, b| Some(a.wrapping_mul(b))).unwrap()
+}
+
+fn saturating_pow(base: i64, exponent: i64) -> i64 {
+    integer_pow(base, exponent, |a, b| Some(a.saturating_mul(b))).unwrap()
+}
+
+// Left shifts overflow when bits other than copies of the sign bit are shifted
+// out. `amount` must not be negative.
+fn checked_shl(value: i64, amount: i64) -> Option<i64> {
+    if amount >= 64 {
+        return if value == 0 { Some(0) } else { None };
+    }
+    let shifted = value << amount;
+    if shifted >> amount == value {
+        Some(shifted)
+    } else {
+        None
+    }
+}
+
+fn wrapping_shl(value: i64, amount: i64) -> i64 {
+    if amount >= 64 {
+        0
+    } else {
+        value << amount
+    }
+}
+
+fn saturating_shl(value: i64, amount: i64) -> i64 {
+    checked_shl(value, amount).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+}
+
+fn evaluate_integer_comparison(
+    left: Integer,
+    right: Integer,
+    infix_expression: &InfixExpression,
+) -> Result<Object, EvalError> {
+    let operator = infix_expression.operator.as_str();
+    if operator == ">" {
+        return Ok(Object::Bool(Bool {
+            value: left.value > right.value,
+        }));
+    } else if operator == "<" {
+        return Ok(Object::Bool(Bool {
+            value: left.value < right.value,
+        }));
+    } else if operator == ">=" {
+        return Ok(Object::Bool(Bool {
+            value: left.value >= right.value,
+        }));
+    } else if operator == "<=" {
+        return Ok(Object::Bool(Bool {
+            value: left.value <= right.value,
+        }));
+    }
+
+    Err(unknown_infix_operator(
+        infix_expression,
+        &Object::Integer(left),
+        &Object::Integer(right),
+    ))
+}
+
+fn evaluate_float_infix_expression(
+    left_value: f64,
+    right_value: f64,
+    left: &Object,
+    right: &Object,
+    infix_expression: &InfixExpression,
+) -> Result<Object, EvalError> {
+    let value = match infix_expression.operator.as_str() {
+        "+" => left_value + right_value,
+        "-" => left_value - right_value,
+        "*" => left_value * right_value,
+        "/" => {
+            if right_value == 0.0 {
+                return Err(EvalError::DivisionByZero {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            left_value / right_value
+        }
+        "%" => {
+            if right_value == 0.0 {
+                return Err(EvalError::DivisionByZero {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            left_value % right_value
+        }
+        "**" => left_value.powf(right_value),
+        "<" => return Ok(Object::new_bool(left_value < right_value)),
+        ">" => return Ok(Object::new_bool(left_value > right_value)),
+        "<=" => return Ok(Object::new_bool(left_value <= right_value)),
+        ">=" => return Ok(Object::new_bool(left_value >= right_value)),
+        "==" => return Ok(Object::new_bool(left_value == right_value)),
+        "!=" => return Ok(Object::new_bool(left_value != right_value)),
+        _ => return Err(unknown_infix_operator(infix_expression, left, right)),
+    };
+    // Infinity and NaN would print as text that does not parse back.
+    if !value.is_finite() {
+        return Err(EvalError::FloatOverflow {
+            node: Box::new(Expression::InfixExpression(infix_expression.clone())),
         });
+    }
+    Ok(Object::Float(Float { value: value }))
+}
+
+// Promoted results of `**` and `<<` larger than this many bits are reported as
+// an overflow instead of exhausting memory. Other operators grow their result
+// by at most the size of the other operand.
+const MAX_BIG_INTEGER_BITS: u64 = 1 << 22;
+
+// A lower bound on the bits of `left ** exponent`.
+fn pow_bits(left: &BigInt, exponent: u32) -> u64 {
+    (left.bits().saturating_sub(1)).saturating_mul(u64::from(exponent))
+}
+
+fn evaluate_big_integer_infix_expression(
+    left_value: BigInt,
+    right_value: BigInt,
+    left: &Object,
+    right: &Object,
+    infix_expression: &InfixExpression,
+) -> Result<Object, EvalError> {
+    let value = match infix_expression.operator.as_str() {
+        "+" => left_value + right_value,
+        "-" => left_value - right_value,
+        "*" => left_value * right_value,
+        "/" => {
+            if right_value.is_zero() {
+                return Err(EvalError::DivisionByZero {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            left_value / right_value
+        }
+        "%" => {
+            if right_value.is_zero() {
+                return Err(EvalError::DivisionByZero {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            left_value % right_value
+        }
+        "**" => {
+            if right_value.is_negative() {
+                return Err(EvalError::NegativeExponent {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            match right_value.to_u32() {
+                Some(exponent) if pow_bits(&left_value, exponent) <= MAX_BIG_INTEGER_BITS => {
+                    left_value.pow(exponent)
+                }
+                _ if left_value.is_zero() || left_value.is_one() => left_value,
+                _ if left_value == -BigInt::one() => {
+                    if (right_value % 2u32).is_zero() {
+                        BigInt::one()
+                    } else {
+                        left_value
+                    }
+                }
+                _ => return Err(integer_overflow(infix_expression)),
+            }
+        }
+        "<<" | ">>" => {
+            if right_value.is_negative() {
+                return Err(EvalError::NegativeShift {
+                    node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+                });
+            }
+            match (right_value.to_usize(), infix_expression.operator.as_str()) {
+                (_, _) if left_value.is_zero() => left_value,
+                (Some(amount), "<<")
+                    if left_value.bits().saturating_add(amount as u64) <= MAX_BIG_INTEGER_BITS =>
+                {
+                    left_value << amount
+                }
+                (Some(amount), ">>") => left_value >> amount,
+                (None, ">>") if left_value.is_negative() => -BigInt::one(),
+                (None, ">>") => BigInt::zero(),
+                _ => return Err(integer_overflow(infix_expression)),
+            }
+        }
+        "&" => left_value & right_value,
+        "|" => left_value | right_value,
+        "^" => left_value ^ right_value,
+        "<" => return Ok(Object::new_bool(left_value < right_value)),
+        ">" => return Ok(Object::new_bool(left_value > right_value)),
+        "<=" => return Ok(Object::new_bool(left_value <= right_value)),
+        ">=" => return Ok(Object::new_bool(left_value >= right_value)),
+        "==" => return Ok(Object::new_bool(left_value == right_value)),
+        "!=" => return Ok(Object::new_bool(left_value != right_value)),
+        _ => return Err(unknown_infix_operator(infix_expression, left, right)),
+    };
+    Ok(BigInteger::normalize(value))
+}
+
+fn to_f64(object: &Object) -> Option<f64> {
+    match object {
+        Object::Integer(integer) => Some(integer.value as f64),
+        Object::BigInteger(big_integer) => big_integer.value.to_f64(),
+        Object::Float(float) => Some(float.value),
+        _ => None,
+    }
+}
+
+fn to_big_int(object: &Object) -> Option<BigInt> {
+    match object {
+        Object::Integer(integer) => Some(BigInt::from(integer.value)),
+        Object::BigInteger(big_integer) => Some(big_integer.value.clone()),
+        _ => None,
+    }
+}
+
+fn evaluate_string_infix_expression(
+    left: &Str,
+    right: &Str,
+    infix_expression: &InfixExpression,
+) -> Result<Object, EvalError> {
+    let operator = infix_expression.operator.as_str();
+    if operator == "+" {
+        let mut value = left.value.clone();
+        value.push_str(right.value.as_str());
+        return Ok(Object::String(Str { value: value }));
     } else if operator == ">" {
-        return Object::Bool(Bool {
+        return Ok(Object::Bool(Bool {
             value: left.value > right.value,
-        });
+        }));
     } else if operator == "<" {
-        return Object::Bool(Bool {
+        return Ok(Object::Bool(Bool {
             value: left.value < right.value,
-        });
+        }));
+    } else if operator == ">=" {
+        return Ok(Object::Bool(Bool {
+            value: left.value >= right.value,
+        }));
+    } else if operator == "<=" {
+        return Ok(Object::Bool(Bool {
+            value: left.value <= right.value,
+        }));
     }
 
-    Object::Null(NULL)
+    Err(unknown_infix_operator(
+        infix_expression,
+        &Object::String(left.clone()),
+        &Object::String(right.clone()),
+    ))
+}
+
+fn integer_overflow(infix_expression: &InfixExpression) -> EvalError {
+    EvalError::IntegerOverflow {
+        operator: infix_expression.operator.clone(),
+        node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+    }
+}
+
+fn unknown_infix_operator(
+    infix_expression: &InfixExpression,
+    left: &Object,
+    right: &Object,
+) -> EvalError {
+    EvalError::UnknownInfixOperator {
+        operator: infix_expression.operator.clone(),
+        left: left.object_type(),
+        right: right.object_type(),
+        node: Box::new(Expression::InfixExpression(infix_expression.clone())),
+    }
 }
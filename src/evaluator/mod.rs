@@ -1,2 +1,7 @@
+pub mod builtins;
+pub mod config;
+pub mod eval_error;
 pub mod evaluate;
+mod evaluate_assign_expression;
+mod evaluate_call_expression;
 mod evaluate_infix_expression;
@@ -0,0 +1,145 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::{node::Node, program::Program},
+    evaluator::{
+        config::{Config, OverflowPolicy},
+        eval_error::EvalError,
+        evaluate::evaluate_node,
+    },
+    lexer::Lexer,
+    object::{
+        builtin::Builtin, convert::IntoHostFunction, environment::Environment, hash::Hash,
+        hash_key::HashKey, object::Object,
+    },
+    parser::parser::Parser,
+};
+
+/// An interpreter session. Bindings made by one call to `eval` remain visible
+/// to every later call on the same instance.
+pub struct Interpreter {
+    env: Rc<RefCell<Environment>>,
+    config: Config,
+}
+
+impl Interpreter {
+    pub fn new() -> Interpreter {
+        Interpreter::with_config(Config::default())
+    }
+
+    pub fn with_config(config: Config) -> Interpreter {
+        Interpreter {
+            env: Rc::new(RefCell::new(Environment::new())),
+            config: config,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    pub fn env(&self) -> Rc<RefCell<Environment>> {
+        Rc::clone(&self.env)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Object> {
+        self.env.borrow().get(name)
+    }
+
+    pub fn set(&mut self, name: &str, value: Object) {
+        self.env.borrow_mut().set(String::from(name), value);
+    }
+
+    /// Binds a Rust closure under `name` so scripts can call it like a builtin.
+    pub fn register_function<F>(&mut self, name: &str, function: F)
+    where
+        F: Fn(&[Object]) -> Result<Object, EvalError> + 'static,
+    {
+        self.set(name, Object::Builtin(Builtin::new(name, function)));
+    }
+
+    /// Adds a Rust closure to the hash bound to `namespace`, creating the hash
+    /// if needed. Scripts call it as `namespace["name"](...)`.
+    pub fn register_namespaced_function<F>(&mut self, namespace: &str, name: &str, function: F)
+    where
+        F: Fn(&[Object]) -> Result<Object, EvalError> + 'static,
+    {
+        let builtin = Builtin::new(format!("{}.{}", namespace, name).as_str(), function);
+        self.set_namespaced(namespace, name, Object::Builtin(builtin));
+    }
+
+    /// Like `register_function`, but for a function with Rust-typed
+    /// parameters. Arguments are converted with `FromObject` and the result
+    /// with `ToObject`.
+    pub fn register_typed_function<F, Args>(&mut self, name: &str, function: F)
+    where
+        F: IntoHostFunction<Args>,
+    {
+        let builtin = Builtin::from_function(name, function.into_host_function());
+        self.set(name, Object::Builtin(builtin));
+    }
+
+    /// Like `register_namespaced_function`, but for a function with
+    /// Rust-typed parameters.
+    pub fn register_typed_namespaced_function<F, Args>(
+        &mut self,
+        namespace: &str,
+        name: &str,
+        function: F,
+    ) where
+        F: IntoHostFunction<Args>,
+    {
+        let builtin = Builtin::from_function(
+            format!("{}.{}", namespace, name).as_str(),
+            function.into_host_function(),
+        );
+        self.set_namespaced(namespace, name, Object::Builtin(builtin));
+    }
+
+    fn set_namespaced(&mut self, namespace: &str, name: &str, value: Object) {
+        let mut hash = match self.get(namespace) {
+            Some(Object::Hash(hash)) => hash,
+            _ => Hash::new(),
+        };
+        hash.insert(
+            HashKey::String(String::from(name)),
+            Object::new_string(name),
+            value,
+        );
+        self.set(namespace, Object::Hash(hash));
+    }
+
+    pub fn eval(&mut self, input: &str) -> Result<Object, EvalError> {
+        self.eval_source(input, None)
+    }
+
+    /// Like `eval`, but error positions name `file` as the source.
+    pub fn eval_file(&mut self, input: &str, file: &str) -> Result<Object, EvalError> {
+        self.eval_source(input, Some(file))
+    }
+
+    fn eval_source(&mut self, input: &str, file: Option<&str>) -> Result<Object, EvalError> {
+        let mut l = match file {
+            Some(file) => Lexer::with_file(input, file),
+            None => Lexer::new(input),
+        };
+        let mut p = Parser::new(&mut l);
+        p.tracer.on = false;
+        p.big_integers = self.config.overflow_policy == OverflowPolicy::Promote;
+        let program: Program = p.parse_program();
+        if !p.errors.is_empty() {
+            return Err(EvalError::ParseErrors { errors: p.errors });
+        }
+        evaluate_node(Node::Program(program), &self.env, &self.config)
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Interpreter::new()
+    }
+}
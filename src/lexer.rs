@@ -1,12 +1,28 @@
-use token::{Token, TokenType};
+use std::rc::Rc;
+
+use token::{Span, Token, TokenType};
 
 use crate::token;
 
+// Walks the input by byte offset: `position` is the offset of `ch` and
+// `read_position` the offset of the character after it. Both always fall on
+// UTF-8 character boundaries, so each character is decoded exactly once.
 pub struct Lexer<'a> {
     input: &'a str,
-    position: i32,
-    read_position: i32,
+    position: usize,
+    read_position: usize,
     ch: Option<char>,
+    // 1-based line and column of `ch`, and the name of the source, for spans.
+    line: usize,
+    column: usize,
+    file: Option<Rc<str>>,
+    // Diagnostics such as unterminated comments; the parser moves these into
+    // its own errors as it reads tokens.
+    pub errors: Vec<String>,
+    // When set, comments are collected into `comments` as COMMENT tokens
+    // instead of being discarded.
+    pub keep_comments: bool,
+    pub comments: Vec<Token>,
 }
 
 impl<'a> Lexer<'a> {
@@ -16,11 +32,24 @@ impl<'a> Lexer<'a> {
             position: 0,
             read_position: 0,
             ch: None,
+            line: 1,
+            column: 1,
+            file: None,
+            errors: vec![],
+            keep_comments: false,
+            comments: vec![],
         };
         l.read_char();
         l
     }
 
+    // Like `new`, but the spans of the tokens read name `file` as their source.
+    pub fn with_file(input: &'a str, file: &str) -> Lexer<'a> {
+        let mut l = Lexer::new(input);
+        l.file = Some(Rc::from(file));
+        l
+    }
+
     pub fn read_tokens(&mut self) -> Vec<Token> {
         let mut token = self.next_token();
         let mut tokens: Vec<Token> = vec![];
@@ -39,8 +68,37 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_token(&mut self) -> Token {
-        self.skip_whitespace();
+        self.skip_trivia();
+        let start = self.location();
+        let token = self.read_token();
+        Token {
+            span: self.span_from(start),
+            ..token
+        }
+    }
 
+    // The position of `ch` as an empty span.
+    fn location(&self) -> Span {
+        Span {
+            file: self.file.clone(),
+            start: self.position,
+            end: self.position,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    // Extends a span taken with `location` up to the current position.
+    fn span_from(&self, start: Span) -> Span {
+        Span {
+            end: self.position,
+            ..start
+        }
+    }
+
+    // Reads the token at `ch`. Its span is left empty for `next_token` to fill
+    // in.
+    fn read_token(&mut self) -> Token {
         let multi_char_token = self.read_multi_char_tokens();
         if multi_char_token.is_some() {
             return multi_char_token.unwrap();
@@ -53,103 +111,328 @@ impl<'a> Lexer<'a> {
             return Token {
                 token_type: token_type.unwrap(),
                 literal: literal,
+                span: Span::default(),
             };
         }
 
-        if self.ch.unwrap_or('0').is_alphabetic() {
+        if self.ch == Some('"') {
+            return self.read_string();
+        }
+
+        if is_identifier_start(self.ch) {
             let literal = self.read_identifier();
             let token_type = token::lookup_keyword(literal.as_str());
             return Token {
                 token_type: token_type,
                 literal: literal,
+                span: Span::default(),
             };
         }
-        if self.ch.unwrap_or('a').is_numeric() {
-            return Token {
-                token_type: TokenType::INT,
-                literal: self.read_number(),
-            };
+        if self.ch.unwrap_or('a').is_ascii_digit() {
+            return self.read_number();
         }
-        let s = match self.ch {
-            None => String::from(""),
-            _ => String::from(self.ch.unwrap()),
-        };
-        self.read_char();
-        Token {
-            token_type: TokenType::EOF,
-            literal: s,
+        match self.ch {
+            None => Token {
+                token_type: TokenType::EOF,
+                literal: String::from(""),
+                span: Span::default(),
+            },
+            Some(ch) => {
+                self.errors
+                    .push(format!("{}: illegal character '{}'", self.location(), ch));
+                self.read_char();
+                Token {
+                    token_type: TokenType::ILLEGAL,
+                    literal: String::from(ch),
+                    span: Span::default(),
+                }
+            }
         }
     }
 
-    fn read_identifier(&mut self) -> String {
+    // Skips whitespace, `// line` comments and nestable `/* block */` comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            self.skip_whitespace();
+            let start = self.location();
+            let comment = match (self.ch, self.peek_char()) {
+                (Some('/'), Some('/')) => self.read_line_comment(),
+                (Some('/'), Some('*')) => self.read_block_comment(),
+                _ => return,
+            };
+            if self.keep_comments {
+                let span = self.span_from(start);
+                self.comments.push(Token {
+                    token_type: TokenType::COMMENT,
+                    literal: comment,
+                    span: span,
+                });
+            }
+        }
+    }
+
+    fn read_line_comment(&mut self) -> String {
         let mut s = String::new();
-        while self.ch.unwrap_or_else(|| '0').is_alphabetic() {
+        while self.ch.is_some() && self.ch != Some('\n') {
             s.push(self.ch.unwrap());
             self.read_char();
         }
-        return s;
+        s
     }
 
-    fn read_number(&mut self) -> String {
+    fn read_block_comment(&mut self) -> String {
+        let start = self.location();
         let mut s = String::new();
-        while self.ch.unwrap_or('a').is_numeric() {
-            s.push(self.ch.unwrap());
-            self.read_char();
+        let mut depth = 0;
+        loop {
+            match (self.ch, self.peek_char()) {
+                (Some('/'), Some('*')) => {
+                    depth += 1;
+                    s.push_str("/*");
+                    self.read_char();
+                    self.read_char();
+                }
+                (Some('*'), Some('/')) => {
+                    depth -= 1;
+                    s.push_str("*/");
+                    self.read_char();
+                    self.read_char();
+                    if depth == 0 {
+                        return s;
+                    }
+                }
+                (Some(ch), _) => {
+                    s.push(ch);
+                    self.read_char();
+                }
+                (None, _) => {
+                    self.errors
+                        .push(format!("{}: unterminated block comment", start));
+                    return s;
+                }
+            }
         }
-        return s;
     }
 
-    fn read_char(&mut self) {
-        if self.read_position >= self.input.len() as i32 {
-            self.ch = None;
-        } else {
-            let ch = self.input.chars().nth(self.read_position as usize);
-            self.ch = ch;
+    fn read_identifier(&mut self) -> String {
+        let start = self.position;
+        while is_identifier_continue(self.ch) {
+            self.read_char();
         }
-        self.position = self.read_position;
-        self.read_position += 1;
+        String::from(&self.input[start..self.position])
     }
 
-    fn read_multi_char_tokens(&mut self) -> Option<Token> {
-        match self.ch.unwrap_or('0') {
-            '=' => {
-                if self.peek_char().unwrap_or('0') == '=' {
+    // Reads a double-quoted string literal, decoding escape sequences. The
+    // token literal is the decoded value; an unterminated string or an invalid
+    // escape yields an ILLEGAL token carrying the raw source text instead.
+    fn read_string(&mut self) -> Token {
+        let start = self.location();
+        let mut raw = String::from('"');
+        let mut value = String::new();
+        let mut valid = true;
+        self.read_char();
+        loop {
+            match self.ch {
+                None => {
+                    self.errors.push(format!("{}: unterminated string", start));
+                    return Token {
+                        token_type: TokenType::ILLEGAL,
+                        literal: raw,
+                        span: Span::default(),
+                    };
+                }
+                Some('"') => {
+                    raw.push('"');
                     self.read_char();
+                    break;
+                }
+                Some('\\') => {
+                    raw.push('\\');
                     self.read_char();
-                    return Some(Token {
-                        token_type: TokenType::EQ,
-                        literal: String::from("=="),
-                    });
+                    match self.read_escape(&mut raw) {
+                        Some(ch) => value.push(ch),
+                        None => valid = false,
+                    }
                 }
-                None
-            }
-            '!' => {
-                if self.peek_char().unwrap_or('0') == '=' {
+                Some(ch) => {
+                    raw.push(ch);
+                    value.push(ch);
                     self.read_char();
+                }
+            }
+        }
+        if !valid {
+            self.errors
+                .push(format!("{}: invalid escape sequence in string", start));
+            return Token {
+                token_type: TokenType::ILLEGAL,
+                literal: raw,
+                span: Span::default(),
+            };
+        }
+        Token {
+            token_type: TokenType::STRING,
+            literal: value,
+            span: Span::default(),
+        }
+    }
+
+    // Reads the escape sequence following a backslash, appending the source
+    // text to `raw`. Returns `None` for an unknown or malformed escape.
+    fn read_escape(&mut self, raw: &mut String) -> Option<char> {
+        let ch = self.ch?;
+        raw.push(ch);
+        self.read_char();
+        match ch {
+            'n' => Some('\n'),
+            't' => Some('\t'),
+            'r' => Some('\r'),
+            '"' => Some('"'),
+            '\\' => Some('\\'),
+            'u' => {
+                if self.ch != Some('{') {
+                    return None;
+                }
+                raw.push('{');
+                self.read_char();
+                let mut digits = String::new();
+                while self.ch.map_or(false, |ch| ch.is_ascii_hexdigit()) {
+                    digits.push(self.ch.unwrap());
                     self.read_char();
-                    return Some(Token {
-                        token_type: TokenType::NotEq,
-                        literal: String::from("!="),
-                    });
                 }
-                None
+                raw.push_str(digits.as_str());
+                if self.ch != Some('}') {
+                    return None;
+                }
+                raw.push('}');
+                self.read_char();
+                if digits.is_empty() || digits.len() > 6 {
+                    return None;
+                }
+                u32::from_str_radix(digits.as_str(), 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
             }
             _ => None,
         }
     }
 
-    fn peek_char(&self) -> Option<char> {
-        if self.read_position as usize >= self.input.len() {
-            return None;
+    // Reads an integer, or a float when the digits are followed by a fraction
+    // (`3.14`) and/or an exponent (`1e-9`). A `.` or `e` that is not followed
+    // by digits is left for the next token.
+    fn read_number(&mut self) -> Token {
+        let mut s = self.read_digits();
+        let mut token_type = TokenType::INT;
+
+        if self.ch == Some('.') && self.peek_nth_char(0).map_or(false, |c| c.is_ascii_digit()) {
+            token_type = TokenType::FLOAT;
+            s.push('.');
+            self.read_char();
+            s.push_str(self.read_digits().as_str());
+        }
+
+        if self.ch == Some('e') || self.ch == Some('E') {
+            let exponent_digits_at = match self.peek_nth_char(0) {
+                Some('+') | Some('-') => 1,
+                _ => 0,
+            };
+            if self
+                .peek_nth_char(exponent_digits_at)
+                .map_or(false, |c| c.is_ascii_digit())
+            {
+                token_type = TokenType::FLOAT;
+                for _ in 0..=exponent_digits_at {
+                    s.push(self.ch.unwrap());
+                    self.read_char();
+                }
+                s.push_str(self.read_digits().as_str());
+            }
+        }
+
+        Token {
+            token_type: token_type,
+            literal: s,
+            span: Span::default(),
         }
-        self.input.chars().nth(self.read_position as usize)
     }
 
-    fn peek_token(&self) -> Option<char> {
-        if self.read_position as usize >= self.input.len() {
-            return None;
+    fn read_digits(&mut self) -> String {
+        let start = self.position;
+        while self.ch.unwrap_or('a').is_ascii_digit() {
+            self.read_char();
         }
-        self.input.chars().nth(self.read_position as usize)
+        String::from(&self.input[start..self.position])
+    }
+
+    fn read_char(&mut self) {
+        match self.ch {
+            Some('\n') => {
+                self.line += 1;
+                self.column = 1;
+            }
+            Some(_) => self.column += 1,
+            None => {}
+        }
+        self.position = self.read_position;
+        self.ch = self.input[self.read_position..].chars().next();
+        if let Some(ch) = self.ch {
+            self.read_position += ch.len_utf8();
+        }
+    }
+
+    fn read_multi_char_tokens(&mut self) -> Option<Token> {
+        let (token_type, literal) = match (self.ch?, self.peek_char()?) {
+            ('=', '=') => (TokenType::EQ, "=="),
+            ('!', '=') => (TokenType::NotEq, "!="),
+            ('<', '=') => (TokenType::LtEq, "<="),
+            ('>', '=') => (TokenType::GtEq, ">="),
+            ('<', '<') => (TokenType::ShiftLeft, "<<"),
+            ('>', '>') => (TokenType::ShiftRight, ">>"),
+            ('&', '&') => (TokenType::AND, "&&"),
+            ('|', '|') => (TokenType::OR, "||"),
+            ('*', '*') => (TokenType::POWER, "**"),
+            ('+', '=') => (TokenType::PlusAssign, "+="),
+            ('-', '=') => (TokenType::MinusAssign, "-="),
+            ('*', '=') => (TokenType::AsteriskAssign, "*="),
+            ('/', '=') => (TokenType::SlashAssign, "/="),
+            _ => return None,
+        };
+        self.read_char();
+        self.read_char();
+        Some(Token {
+            token_type: token_type,
+            literal: String::from(literal),
+            span: Span::default(),
+        })
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.read_position..].chars().next()
+    }
+
+    // Looks `n` characters past the next one; `peek_nth_char(0)` is `peek_char`.
+    fn peek_nth_char(&self, n: usize) -> Option<char> {
+        self.input[self.read_position..].chars().nth(n)
+    }
+
+    fn peek_token(&self) -> Option<char> {
+        self.peek_char()
+    }
+}
+
+// Identifiers start with a letter or `_` and continue with letters, digits
+// and `_`.
+fn is_identifier_start(ch: Option<char>) -> bool {
+    match ch {
+        Some(ch) => ch.is_alphabetic() || ch == '_',
+        None => false,
+    }
+}
+
+fn is_identifier_continue(ch: Option<char>) -> bool {
+    match ch {
+        Some(ch) => ch.is_alphanumeric() || ch == '_',
+        None => false,
     }
 }
 
@@ -195,7 +478,7 @@ let add = fn(x, y) {
 };
 
 let result = add(five, ten);
-!-/*5;
+!-/ *5;
 5 < 10 > 5;
 
 if (5 < 10) {
@@ -295,4 +578,304 @@ if (5 < 10) {
             i += 1;
         }
     }
+
+    #[test]
+    fn next_token_comparison_and_logical() {
+        let input: &str = "a <= b >= c && d || e < f > g";
+        let tests = vec![
+            (TokenType::IDENT, "a"),
+            (TokenType::LtEq, "<="),
+            (TokenType::IDENT, "b"),
+            (TokenType::GtEq, ">="),
+            (TokenType::IDENT, "c"),
+            (TokenType::AND, "&&"),
+            (TokenType::IDENT, "d"),
+            (TokenType::OR, "||"),
+            (TokenType::IDENT, "e"),
+            (TokenType::LT, "<"),
+            (TokenType::IDENT, "f"),
+            (TokenType::GT, ">"),
+            (TokenType::IDENT, "g"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+    }
+
+    #[test]
+    fn next_token_arithmetic_and_bitwise() {
+        let input: &str = "a % b ** c & d | e ^ ~f << 1 >> 2 * 3";
+        let tests = vec![
+            (TokenType::IDENT, "a"),
+            (TokenType::PERCENT, "%"),
+            (TokenType::IDENT, "b"),
+            (TokenType::POWER, "**"),
+            (TokenType::IDENT, "c"),
+            (TokenType::AMPERSAND, "&"),
+            (TokenType::IDENT, "d"),
+            (TokenType::PIPE, "|"),
+            (TokenType::IDENT, "e"),
+            (TokenType::CARET, "^"),
+            (TokenType::TILDE, "~"),
+            (TokenType::IDENT, "f"),
+            (TokenType::ShiftLeft, "<<"),
+            (TokenType::INT, "1"),
+            (TokenType::ShiftRight, ">>"),
+            (TokenType::INT, "2"),
+            (TokenType::ASTERISK, "*"),
+            (TokenType::INT, "3"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+    }
+
+    #[test]
+    fn next_token_comments() {
+        let input: &str = "let x = 1; // trailing comment
+// full line comment
+/* block */ x /* multi
+line */ + /* outer /* nested */ still outer */ 2 / 3 // end";
+        let tests = vec![
+            (TokenType::LET, "let"),
+            (TokenType::IDENT, "x"),
+            (TokenType::ASSIGN, "="),
+            (TokenType::INT, "1"),
+            (TokenType::SEMICOLON, ";"),
+            (TokenType::IDENT, "x"),
+            (TokenType::PLUS, "+"),
+            (TokenType::INT, "2"),
+            (TokenType::SLASH, "/"),
+            (TokenType::INT, "3"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+        assert!(l.errors.is_empty());
+        assert!(l.comments.is_empty());
+    }
+
+    #[test]
+    fn keep_comments() {
+        let input: &str = "// one\n1 /* two /* three */ */ 2 // four";
+        let mut l = Lexer::new(input);
+        l.keep_comments = true;
+        let tokens = l.read_tokens();
+        assert_eq!(3, tokens.len());
+        let comments: Vec<String> = l.comments.iter().map(|t| t.literal.clone()).collect();
+        assert_eq!(vec!["// one", "/* two /* three */ */", "// four"], comments);
+        assert!(l
+            .comments
+            .iter()
+            .all(|t| t.token_type == TokenType::COMMENT));
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        let input: &str = "1 /* open /* nested */ never closed";
+        let mut l = Lexer::new(input);
+        assert_eq!(TokenType::INT, l.next_token().token_type);
+        assert_eq!(TokenType::EOF, l.next_token().token_type);
+        assert_eq!(vec!["1:3: unterminated block comment"], l.errors);
+    }
+
+    #[test]
+    fn next_token_identifier() {
+        let input: &str = "my_var x2 _private __init__ snake_case_2 a1b2 _ 3x let_";
+        let tests = vec![
+            (TokenType::IDENT, "my_var"),
+            (TokenType::IDENT, "x2"),
+            (TokenType::IDENT, "_private"),
+            (TokenType::IDENT, "__init__"),
+            (TokenType::IDENT, "snake_case_2"),
+            (TokenType::IDENT, "a1b2"),
+            (TokenType::IDENT, "_"),
+            (TokenType::INT, "3"),
+            (TokenType::IDENT, "x"),
+            (TokenType::IDENT, "let_"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+    }
+
+    #[test]
+    fn next_token_float() {
+        let input: &str = "3.14 0.5 1e-9 2E+3 7e2 1.5e10 1. 2.x 3e 4e+ 5";
+        let tests = vec![
+            (TokenType::FLOAT, "3.14"),
+            (TokenType::FLOAT, "0.5"),
+            (TokenType::FLOAT, "1e-9"),
+            (TokenType::FLOAT, "2E+3"),
+            (TokenType::FLOAT, "7e2"),
+            (TokenType::FLOAT, "1.5e10"),
+            (TokenType::INT, "1"),
+            (TokenType::ILLEGAL, "."),
+            (TokenType::INT, "2"),
+            (TokenType::ILLEGAL, "."),
+            (TokenType::IDENT, "x"),
+            (TokenType::INT, "3"),
+            (TokenType::IDENT, "e"),
+            (TokenType::INT, "4"),
+            (TokenType::IDENT, "e"),
+            (TokenType::PLUS, "+"),
+            (TokenType::INT, "5"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+    }
+
+    #[test]
+    fn next_token_string() {
+        let input: &str = r#""foobar" "foo bar" "" "a\nb\t\"c\"\\" "\u{48}\u{1F600}""#;
+        let tests = vec![
+            (TokenType::STRING, "foobar"),
+            (TokenType::STRING, "foo bar"),
+            (TokenType::STRING, ""),
+            (TokenType::STRING, "a\nb\t\"c\"\\"),
+            (TokenType::STRING, "H\u{1F600}"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k);
+            assert_eq!(t.literal, v);
+        }
+    }
+
+    #[test]
+    fn next_token_illegal_string() {
+        let tests = vec![
+            (r#""abc"#, r#""abc"#),
+            (r#""a\qb""#, r#""a\qb""#),
+            (r#""\u{110000}""#, r#""\u{110000}""#),
+            (r#""\u{}""#, r#""\u{}""#),
+            (r#""\u41""#, r#""\u41""#),
+        ];
+        for (input, literal) in tests {
+            let mut l = Lexer::new(input);
+            let t = l.next_token();
+            assert_eq!(t.token_type, TokenType::ILLEGAL, "{}", input);
+            assert_eq!(t.literal, literal, "{}", input);
+            assert_eq!(1, l.errors.len(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn next_token_multibyte() {
+        let input: &str = "let ñame = \"héllo 😀\"; ñame € 1";
+        let tests = vec![
+            (TokenType::LET, "let"),
+            (TokenType::IDENT, "ñame"),
+            (TokenType::ASSIGN, "="),
+            (TokenType::STRING, "héllo 😀"),
+            (TokenType::SEMICOLON, ";"),
+            (TokenType::IDENT, "ñame"),
+            (TokenType::ILLEGAL, "€"),
+            (TokenType::INT, "1"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+        // Columns count characters, not bytes.
+        assert_eq!(vec!["1:28: illegal character '€'"], l.errors);
+    }
+
+    #[test]
+    fn next_token_illegal_character() {
+        let input: &str = "let a = 1 @ 2; $b";
+        let tests = vec![
+            (TokenType::LET, "let"),
+            (TokenType::IDENT, "a"),
+            (TokenType::ASSIGN, "="),
+            (TokenType::INT, "1"),
+            (TokenType::ILLEGAL, "@"),
+            (TokenType::INT, "2"),
+            (TokenType::SEMICOLON, ";"),
+            (TokenType::ILLEGAL, "$"),
+            (TokenType::IDENT, "b"),
+            (TokenType::EOF, ""),
+        ];
+
+        let mut l = Lexer::new(input);
+
+        for (k, v) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, k, "{}", v);
+            assert_eq!(t.literal, v);
+        }
+        assert_eq!(
+            vec!["1:11: illegal character '@'", "1:16: illegal character '$'"],
+            l.errors
+        );
+    }
+
+    #[test]
+    fn next_token_spans() {
+        let input: &str = "let é = 10;\n  /* c */ x\n\"s\"";
+        let tests = vec![
+            (TokenType::LET, 0, 3, 1, 1),
+            (TokenType::IDENT, 4, 6, 1, 5),
+            (TokenType::ASSIGN, 7, 8, 1, 7),
+            (TokenType::INT, 9, 11, 1, 9),
+            (TokenType::SEMICOLON, 11, 12, 1, 11),
+            (TokenType::IDENT, 23, 24, 2, 11),
+            (TokenType::STRING, 25, 28, 3, 1),
+            (TokenType::EOF, 28, 28, 3, 4),
+        ];
+
+        let mut l = Lexer::with_file(input, "main.mk");
+
+        for (token_type, start, end, line, column) in tests {
+            let t = l.next_token();
+            assert_eq!(t.token_type, token_type);
+            assert_eq!(
+                (start, end, line, column),
+                (t.span.start, t.span.end, t.span.line, t.span.column),
+                "{}",
+                t
+            );
+            assert_eq!(Some("main.mk"), t.span.file.as_deref());
+        }
+    }
 }
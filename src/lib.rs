@@ -5,7 +5,8 @@ extern crate scopeguard;
 
 pub mod ast;
 pub mod evaluator;
+pub mod interpreter;
 pub mod lexer;
 pub mod object;
 pub mod parser;
-mod token;
+pub mod token;
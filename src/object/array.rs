@@ -0,0 +1,36 @@
+use super::{object::Object, object_trait::ObjectTrait, truthiness_trait::Truthiness};
+
+#[derive(Clone, Debug, Eq)]
+pub struct Array {
+    pub elements: Vec<Object>,
+}
+
+// Arrays compare structurally, element by element.
+impl PartialEq for Array {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements
+    }
+}
+
+impl ObjectTrait for Array {
+    fn string(&self) -> String {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| element.string())
+            .collect::<Vec<_>>();
+        format!("[{}]", elements.join(", "))
+    }
+}
+
+impl Truthiness for Array {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
+
+impl Array {
+    pub fn new(elements: Vec<Object>) -> Array {
+        Array { elements: elements }
+    }
+}
@@ -0,0 +1,47 @@
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use super::{
+    hash_key::HashKey, hashable_trait::Hashable, integer::Integer, object::Object,
+    object_trait::ObjectTrait, truthiness_trait::Truthiness,
+};
+
+// An integer outside the range of `i64`. Use `BigInteger::normalize` to build
+// one so values that fit in an `i64` stay plain `Integer`s.
+#[derive(Clone, Debug, Eq)]
+pub struct BigInteger {
+    pub value: BigInt,
+}
+
+impl PartialEq for BigInteger {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl ObjectTrait for BigInteger {
+    fn string(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl Hashable for BigInteger {
+    fn hash_key(&self) -> HashKey {
+        HashKey::BigInteger(self.value.clone())
+    }
+}
+
+impl Truthiness for BigInteger {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
+
+impl BigInteger {
+    pub fn normalize(value: BigInt) -> Object {
+        match value.to_i64() {
+            Some(value) => Object::Integer(Integer::new(value)),
+            None => Object::BigInteger(BigInteger { value: value }),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::{object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use super::{
+    hash_key::HashKey, hashable_trait::Hashable, object_trait::ObjectTrait,
+    truthiness_trait::Truthiness,
+};
 
 #[derive(Clone, Copy, Debug, Eq)]
 pub struct Bool {
@@ -11,6 +14,12 @@ impl ObjectTrait for Bool {
     }
 }
 
+impl Hashable for Bool {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Bool(self.value)
+    }
+}
+
 impl Truthiness for Bool {
     fn is_truthy(&self) -> bool {
         self.value
@@ -0,0 +1,58 @@
+use std::{fmt, rc::Rc};
+
+use super::{object::Object, object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use crate::evaluator::eval_error::EvalError;
+
+pub type BuiltinFunction = Rc<dyn Fn(&[Object]) -> Result<Object, EvalError>>;
+
+#[derive(Clone)]
+pub struct Builtin {
+    pub name: String,
+    pub function: BuiltinFunction,
+}
+
+// Closures cannot be compared, so builtins are equal when their names are.
+impl PartialEq for Builtin {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Builtin {}
+
+impl fmt::Debug for Builtin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Builtin({})", self.name)
+    }
+}
+
+impl ObjectTrait for Builtin {
+    fn string(&self) -> String {
+        format!("builtin function {}", self.name)
+    }
+}
+
+impl Truthiness for Builtin {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
+
+impl Builtin {
+    pub fn from_function(name: &str, function: BuiltinFunction) -> Builtin {
+        Builtin {
+            name: String::from(name),
+            function: function,
+        }
+    }
+
+    pub fn new<F>(name: &str, function: F) -> Builtin
+    where
+        F: Fn(&[Object]) -> Result<Object, EvalError> + 'static,
+    {
+        Builtin {
+            name: String::from(name),
+            function: Rc::new(function),
+        }
+    }
+}
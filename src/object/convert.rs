@@ -0,0 +1,319 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash,
+    iter::FromIterator,
+    rc::Rc,
+};
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use super::{
+    array::Array, big_integer::BigInteger, builtin::BuiltinFunction, hash::Hash, hash_key::HashKey,
+    hashable_trait::Hashable, object::Object, object_type::ObjectType,
+};
+use crate::evaluator::eval_error::EvalError;
+
+/// Converts a Rust value into a Monkey object.
+pub trait ToObject {
+    fn to_object(self) -> Object;
+}
+
+/// Extracts a Rust value from a Monkey object, failing with
+/// `EvalError::ConversionError` when the object has the wrong type or a value
+/// the Rust type cannot hold.
+pub trait FromObject: Sized {
+    fn from_object(object: &Object) -> Result<Self, EvalError>;
+}
+
+fn conversion_error(expected: ObjectType, object: &Object) -> EvalError {
+    EvalError::ConversionError {
+        expected: expected,
+        got: object.object_type(),
+        value: None,
+        node: None,
+    }
+}
+
+fn out_of_range_error(expected: ObjectType, object: &Object) -> EvalError {
+    EvalError::ConversionError {
+        expected: expected,
+        got: object.object_type(),
+        value: Some(object.string()),
+        node: None,
+    }
+}
+
+impl ToObject for Object {
+    fn to_object(self) -> Object {
+        self
+    }
+}
+
+impl FromObject for Object {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        Ok(object.clone())
+    }
+}
+
+impl ToObject for i64 {
+    fn to_object(self) -> Object {
+        Object::new_integer(self)
+    }
+}
+
+impl FromObject for i64 {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Integer(integer) => Ok(integer.value),
+            Object::BigInteger(_) => Err(out_of_range_error(ObjectType::INTEGER, object)),
+            _ => Err(conversion_error(ObjectType::INTEGER, object)),
+        }
+    }
+}
+
+impl ToObject for BigInt {
+    fn to_object(self) -> Object {
+        BigInteger::normalize(self)
+    }
+}
+
+impl FromObject for BigInt {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Integer(integer) => Ok(BigInt::from(integer.value)),
+            Object::BigInteger(big_integer) => Ok(big_integer.value.clone()),
+            _ => Err(conversion_error(ObjectType::INTEGER, object)),
+        }
+    }
+}
+
+impl Hashable for i64 {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Integer(*self)
+    }
+}
+
+// Unlike evaluated floats, `NaN` and infinities are kept as they are, so a host
+// function can hand them to a script. They print as text that does not parse
+// back, and any arithmetic on them reports `EvalError::FloatOverflow`.
+impl ToObject for f64 {
+    fn to_object(self) -> Object {
+        Object::new_float(self)
+    }
+}
+
+// Integers widen to floats, matching the evaluator's numeric promotion.
+impl FromObject for f64 {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Float(float) => Ok(float.value),
+            Object::Integer(integer) => Ok(integer.value as f64),
+            Object::BigInteger(big_integer) => match big_integer.value.to_f64() {
+                Some(value) if value.is_finite() => Ok(value),
+                _ => Err(out_of_range_error(ObjectType::FLOAT, object)),
+            },
+            _ => Err(conversion_error(ObjectType::FLOAT, object)),
+        }
+    }
+}
+
+impl ToObject for bool {
+    fn to_object(self) -> Object {
+        Object::new_bool(self)
+    }
+}
+
+impl FromObject for bool {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Bool(bool_object) => Ok(bool_object.value),
+            _ => Err(conversion_error(ObjectType::BOOLEAN, object)),
+        }
+    }
+}
+
+impl Hashable for bool {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Bool(*self)
+    }
+}
+
+impl ToObject for () {
+    fn to_object(self) -> Object {
+        Object::null()
+    }
+}
+
+impl FromObject for () {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Null(_) => Ok(()),
+            _ => Err(conversion_error(ObjectType::NULL, object)),
+        }
+    }
+}
+
+impl ToObject for String {
+    fn to_object(self) -> Object {
+        Object::new_string(self.as_str())
+    }
+}
+
+impl ToObject for &str {
+    fn to_object(self) -> Object {
+        Object::new_string(self)
+    }
+}
+
+impl FromObject for String {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::String(string) => Ok(string.value.clone()),
+            _ => Err(conversion_error(ObjectType::STRING, object)),
+        }
+    }
+}
+
+impl Hashable for String {
+    fn hash_key(&self) -> HashKey {
+        HashKey::String(self.clone())
+    }
+}
+
+impl<T: ToObject> ToObject for Vec<T> {
+    fn to_object(self) -> Object {
+        Object::Array(Array::new(
+            self.into_iter()
+                .map(|element| element.to_object())
+                .collect(),
+        ))
+    }
+}
+
+impl<T: FromObject> FromObject for Vec<T> {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Array(array) => array.elements.iter().map(T::from_object).collect(),
+            _ => Err(conversion_error(ObjectType::ARRAY, object)),
+        }
+    }
+}
+
+// `None` maps to `null` in both directions.
+impl<T: ToObject> ToObject for Option<T> {
+    fn to_object(self) -> Object {
+        match self {
+            Some(value) => value.to_object(),
+            None => Object::null(),
+        }
+    }
+}
+
+impl<T: FromObject> FromObject for Option<T> {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        match object {
+            Object::Null(_) => Ok(None),
+            _ => T::from_object(object).map(Some),
+        }
+    }
+}
+
+// Only key types that are themselves hashable in Monkey can be converted. A
+// `HashMap` has no order of its own, so its pairs are inserted sorted by key
+// to keep the resulting hash deterministic; use a `BTreeMap` to make that
+// order explicit.
+impl<K: ToObject + Hashable + Ord, V: ToObject> ToObject for HashMap<K, V> {
+    fn to_object(self) -> Object {
+        let mut pairs = self.into_iter().collect::<Vec<_>>();
+        pairs.sort_by(|(left, _), (right, _)| left.cmp(right));
+        pairs_to_object(pairs)
+    }
+}
+
+impl<K: FromObject + Eq + hash::Hash, V: FromObject> FromObject for HashMap<K, V> {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        pairs_from_object(object)
+    }
+}
+
+impl<K: ToObject + Hashable + Ord, V: ToObject> ToObject for BTreeMap<K, V> {
+    fn to_object(self) -> Object {
+        pairs_to_object(self)
+    }
+}
+
+impl<K: FromObject + Ord, V: FromObject> FromObject for BTreeMap<K, V> {
+    fn from_object(object: &Object) -> Result<Self, EvalError> {
+        pairs_from_object(object)
+    }
+}
+
+fn pairs_to_object<K, V, I>(pairs: I) -> Object
+where
+    K: ToObject + Hashable,
+    V: ToObject,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut hash = Hash::new();
+    for (key, value) in pairs.into_iter() {
+        hash.insert(key.hash_key(), key.to_object(), value.to_object());
+    }
+    Object::Hash(hash)
+}
+
+fn pairs_from_object<K, V, C>(object: &Object) -> Result<C, EvalError>
+where
+    K: FromObject,
+    V: FromObject,
+    C: FromIterator<(K, V)>,
+{
+    match object {
+        Object::Hash(hash) => hash
+            .pairs
+            .iter()
+            .map(|pair| Ok((K::from_object(&pair.key)?, V::from_object(&pair.value)?)))
+            .collect(),
+        _ => Err(conversion_error(ObjectType::HASH, object)),
+    }
+}
+
+/// A Rust function whose parameters implement `FromObject` and whose result
+/// implements `ToObject`. Arguments are checked and converted before the call.
+pub trait IntoHostFunction<Args> {
+    fn into_host_function(self) -> BuiltinFunction;
+}
+
+macro_rules! impl_into_host_function {
+    ($($arg:ident),*) => {
+        impl<F, R, $($arg),*> IntoHostFunction<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> Result<R, EvalError> + 'static,
+            R: ToObject,
+            $($arg: FromObject,)*
+        {
+            #[allow(non_snake_case, unused_variables, unused_mut)]
+            fn into_host_function(self) -> BuiltinFunction {
+                Rc::new(move |arguments: &[Object]| {
+                    let expected = <[&str]>::len(&[$(stringify!($arg)),*]);
+                    if arguments.len() != expected {
+                        return Err(EvalError::ArityMismatch {
+                            expected: expected,
+                            got: arguments.len(),
+                            node: None,
+                        });
+                    }
+                    let mut arguments = arguments.iter();
+                    $(let $arg = $arg::from_object(arguments.next().unwrap())?;)*
+                    Ok(self($($arg),*)?.to_object())
+                })
+            }
+        }
+    };
+}
+
+impl_into_host_function!();
+impl_into_host_function!(A);
+impl_into_host_function!(A, B);
+impl_into_host_function!(A, B, C);
+impl_into_host_function!(A, B, C, D);
@@ -0,0 +1,154 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use super::object::Object;
+
+#[derive(Debug, Clone)]
+pub struct Environment {
+    store: HashMap<String, Object>,
+    outer: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Environment {
+        Environment {
+            store: HashMap::new(),
+            outer: None,
+        }
+    }
+
+    pub fn new_enclosed(outer: Rc<RefCell<Environment>>) -> Environment {
+        Environment {
+            store: HashMap::new(),
+            outer: Some(outer),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Object> {
+        match self.store.get(name) {
+            Some(object) => Some(object.clone()),
+            None => match &self.outer {
+                Some(outer) => outer.borrow().get(name),
+                None => None,
+            },
+        }
+    }
+
+    pub fn set(&mut self, name: String, value: Object) -> Object {
+        self.store.insert(name, value.clone());
+        value
+    }
+
+    // Calls `f` with the nearest existing binding of `name` so it can be
+    // changed in place. Returns `None` when no environment in the chain binds it.
+    pub fn update<T, F>(&mut self, name: &str, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Object) -> T,
+    {
+        match self.store.get_mut(name) {
+            Some(object) => Some(f(object)),
+            None => match &self.outer {
+                Some(outer) => outer.borrow_mut().update(name, f),
+                None => None,
+            },
+        }
+    }
+
+    // Updates the nearest existing binding of `name`. Returns false when no
+    // environment in the chain binds it.
+    pub fn assign(&mut self, name: &str, value: Object) -> bool {
+        match self.store.get_mut(name) {
+            Some(object) => {
+                *object = value;
+                true
+            }
+            None => match &self.outer {
+                Some(outer) => outer.borrow_mut().assign(name, value),
+                None => false,
+            },
+        }
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Environment::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::Environment;
+    use crate::object::object::Object;
+
+    #[test]
+    fn enclosed_lookup() {
+        let outer = Rc::new(RefCell::new(Environment::new()));
+        outer
+            .borrow_mut()
+            .set(String::from("x"), Object::new_integer(1));
+        outer
+            .borrow_mut()
+            .set(String::from("y"), Object::new_integer(2));
+
+        let mut inner = Environment::new_enclosed(Rc::clone(&outer));
+        inner.set(String::from("y"), Object::new_integer(3));
+
+        assert_eq!(Some(Object::new_integer(1)), inner.get("x"));
+        assert_eq!(Some(Object::new_integer(3)), inner.get("y"));
+        assert_eq!(Some(Object::new_integer(2)), outer.borrow().get("y"));
+        assert_eq!(None, inner.get("z"));
+    }
+
+    #[test]
+    fn assign_updates_nearest_binding() {
+        let outer = Rc::new(RefCell::new(Environment::new()));
+        outer
+            .borrow_mut()
+            .set(String::from("x"), Object::new_integer(1));
+        outer
+            .borrow_mut()
+            .set(String::from("y"), Object::new_integer(2));
+
+        let mut inner = Environment::new_enclosed(Rc::clone(&outer));
+        inner.set(String::from("y"), Object::new_integer(3));
+
+        assert!(inner.assign("x", Object::new_integer(10)));
+        assert!(inner.assign("y", Object::new_integer(30)));
+        assert!(!inner.assign("z", Object::new_integer(0)));
+
+        assert_eq!(Some(Object::new_integer(10)), outer.borrow().get("x"));
+        assert_eq!(Some(Object::new_integer(2)), outer.borrow().get("y"));
+        assert_eq!(Some(Object::new_integer(30)), inner.get("y"));
+        assert_eq!(None, inner.get("z"));
+    }
+
+    #[test]
+    fn update_changes_nearest_binding_in_place() {
+        let outer = Rc::new(RefCell::new(Environment::new()));
+        outer.borrow_mut().set(
+            String::from("a"),
+            Object::new_array(vec![Object::new_integer(1)]),
+        );
+
+        let mut inner = Environment::new_enclosed(Rc::clone(&outer));
+        let updated = inner.update("a", |object| match object {
+            Object::Array(array) => {
+                array.elements.push(Object::new_integer(2));
+                array.elements.len()
+            }
+            _ => 0,
+        });
+
+        assert_eq!(Some(2), updated);
+        assert_eq!(None, inner.update("b", |_| 0));
+        assert_eq!(
+            Some(Object::new_array(vec![
+                Object::new_integer(1),
+                Object::new_integer(2)
+            ])),
+            outer.borrow().get("a")
+        );
+    }
+}
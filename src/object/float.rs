@@ -0,0 +1,37 @@
+use super::{object_trait::ObjectTrait, truthiness_trait::Truthiness};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Float {
+    pub value: f64,
+}
+
+// Object equality treats `NaN` as equal to itself so that `Eq` stays
+// reflexive. The `==` operator compares the values directly and follows
+// IEEE 754 instead.
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value || (self.value.is_nan() && other.value.is_nan())
+    }
+}
+
+impl Eq for Float {}
+
+impl ObjectTrait for Float {
+    // The debug format is the shortest representation that parses back to the
+    // same value, and always keeps a decimal point or exponent.
+    fn string(&self) -> String {
+        format!("{:?}", self.value)
+    }
+}
+
+impl Truthiness for Float {
+    fn is_truthy(&self) -> bool {
+        self.value != 0.0
+    }
+}
+
+impl Float {
+    pub fn new(value: f64) -> Float {
+        Float { value: value }
+    }
+}
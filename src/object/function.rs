@@ -0,0 +1,44 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use super::{environment::Environment, object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use crate::ast::{block_statement::BlockStatement, identifier::Identifier, token_node::TokenNode};
+
+#[derive(Clone)]
+pub struct Function {
+    pub parameters: Vec<Identifier>,
+    pub body: Rc<BlockStatement>,
+    pub env: Rc<RefCell<Environment>>,
+}
+
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.env, &other.env) && self.string() == other.string()
+    }
+}
+
+impl Eq for Function {}
+
+// The captured environment may contain this very function, so it is left out
+// of the debug representation to avoid infinite recursion.
+impl fmt::Debug for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Function({})", self.string())
+    }
+}
+
+impl ObjectTrait for Function {
+    fn string(&self) -> String {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.string())
+            .collect::<Vec<_>>();
+        format!("fn({}) {{ {} }}", parameters.join(", "), self.body.string())
+    }
+}
+
+impl Truthiness for Function {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use super::{
+    hash_key::HashKey, object::Object, object_trait::ObjectTrait, truthiness_trait::Truthiness,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashPair {
+    pub key: Object,
+    pub value: Object,
+}
+
+// Pairs are kept in insertion order; `index` maps each key to its position.
+#[derive(Clone, Debug, Default, Eq)]
+pub struct Hash {
+    pub pairs: Vec<HashPair>,
+    index: HashMap<HashKey, usize>,
+}
+
+impl PartialEq for Hash {
+    fn eq(&self, other: &Self) -> bool {
+        self.pairs.len() == other.pairs.len()
+            && self
+                .index
+                .iter()
+                .all(|(key, position)| match other.get(key) {
+                    Some(value) => *value == self.pairs[*position].value,
+                    None => false,
+                })
+    }
+}
+
+impl ObjectTrait for Hash {
+    fn string(&self) -> String {
+        let pairs = self
+            .pairs
+            .iter()
+            .map(|pair| format!("{}: {}", pair.key.string(), pair.value.string()))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", pairs.join(", "))
+    }
+}
+
+impl Truthiness for Hash {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
+
+impl Hash {
+    pub fn new() -> Hash {
+        Hash::default()
+    }
+
+    pub fn get(&self, key: &HashKey) -> Option<&Object> {
+        self.index
+            .get(key)
+            .map(|position| &self.pairs[*position].value)
+    }
+
+    pub fn get_mut(&mut self, key: &HashKey) -> Option<&mut Object> {
+        match self.index.get(key) {
+            Some(position) => Some(&mut self.pairs[*position].value),
+            None => None,
+        }
+    }
+
+    // Re-inserting an existing key replaces its value but keeps its position.
+    pub fn insert(&mut self, hash_key: HashKey, key: Object, value: Object) {
+        match self.index.get(&hash_key) {
+            Some(position) => self.pairs[*position].value = value,
+            None => {
+                self.index.insert(hash_key, self.pairs.len());
+                self.pairs.push(HashPair {
+                    key: key,
+                    value: value,
+                });
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
@@ -0,0 +1,22 @@
+use num_bigint::BigInt;
+
+use super::object_type::ObjectType;
+
+// Keys keep the full value rather than a digest so distinct keys never collide.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum HashKey {
+    Integer(i64),
+    BigInteger(BigInt),
+    Bool(bool),
+    String(String),
+}
+
+impl HashKey {
+    pub fn object_type(&self) -> ObjectType {
+        match self {
+            HashKey::Integer(_) | HashKey::BigInteger(_) => ObjectType::INTEGER,
+            HashKey::Bool(_) => ObjectType::BOOLEAN,
+            HashKey::String(_) => ObjectType::STRING,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use super::hash_key::HashKey;
+
+pub trait Hashable {
+    fn hash_key(&self) -> HashKey;
+}
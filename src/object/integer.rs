@@ -1,4 +1,7 @@
-use super::{object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use super::{
+    hash_key::HashKey, hashable_trait::Hashable, object_trait::ObjectTrait,
+    truthiness_trait::Truthiness,
+};
 
 #[derive(Clone, Copy, Debug, Eq)]
 pub struct Integer {
@@ -17,6 +20,12 @@ impl ObjectTrait for Integer {
     }
 }
 
+impl Hashable for Integer {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Integer(self.value)
+    }
+}
+
 impl Truthiness for Integer {
     fn is_truthy(&self) -> bool {
         self.value != 0
@@ -0,0 +1,36 @@
+use super::{object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use crate::ast::{loop_control_expression::LoopControlExpression, token_node::TokenNode};
+
+// Raised by `break` and `continue` and propagated like a `ReturnValue` until
+// the enclosing loop consumes it. The expression is kept so that a signal
+// escaping every loop can be reported where it was raised.
+#[derive(Clone, Debug)]
+pub struct LoopControl {
+    pub expression: LoopControlExpression,
+}
+
+impl LoopControl {
+    pub fn is_break(&self) -> bool {
+        self.expression.is_break()
+    }
+}
+
+impl PartialEq for LoopControl {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression.token_type() == other.expression.token_type()
+    }
+}
+
+impl Eq for LoopControl {}
+
+impl ObjectTrait for LoopControl {
+    fn string(&self) -> String {
+        String::from("LOOP_CONTROL")
+    }
+}
+
+impl Truthiness for LoopControl {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
@@ -1,7 +1,20 @@
+pub mod array;
+pub mod big_integer;
 pub mod bool;
+pub mod builtin;
+pub mod convert;
+pub mod environment;
+pub mod float;
+pub mod function;
+pub mod hash;
+pub mod hash_key;
+pub mod hashable_trait;
 pub mod integer;
+pub mod loop_control;
 pub mod null;
 pub mod object;
 pub mod object_trait;
+pub mod object_type;
+pub mod string;
 pub mod truthiness_trait;
 pub mod return_value;
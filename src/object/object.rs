@@ -1,12 +1,35 @@
-use super::{bool::Bool, integer::Integer, null::{NULL, Null}, return_value::ReturnValue};
-use super::{object_trait::ObjectTrait, truthiness_trait::Truthiness};
+use super::{
+    array::Array,
+    big_integer::BigInteger,
+    bool::Bool,
+    builtin::Builtin,
+    float::Float,
+    function::Function,
+    hash::Hash,
+    hash_key::HashKey,
+    hashable_trait::Hashable,
+    integer::Integer,
+    loop_control::LoopControl,
+    null::{Null, NULL},
+    return_value::ReturnValue,
+    string::Str,
+};
+use super::{object_trait::ObjectTrait, object_type::ObjectType, truthiness_trait::Truthiness};
 
 #[derive(Clone, Debug, Eq)]
 pub enum Object {
     Integer(Integer),
+    BigInteger(BigInteger),
+    Float(Float),
     Bool(Bool),
     Null(Null),
     ReturnValue(Box<ReturnValue>),
+    LoopControl(Box<LoopControl>),
+    Function(Function),
+    String(Str),
+    Array(Array),
+    Hash(Hash),
+    Builtin(Builtin),
 }
 
 impl PartialEq for Object {
@@ -15,12 +38,36 @@ impl PartialEq for Object {
             (Object::Integer(self_int), Object::Integer(other_int)) => {
                 return self_int == other_int;
             }
+            (Object::BigInteger(self_big), Object::BigInteger(other_big)) => {
+                return self_big == other_big;
+            }
+            (Object::Float(self_float), Object::Float(other_float)) => {
+                return self_float == other_float;
+            }
             (Object::Bool(self_bool), Object::Bool(other_bool)) => {
                 return self_bool == other_bool;
             }
             (Object::Null(_), Object::Null(_)) => {
                 return true;
             }
+            (Object::LoopControl(self_control), Object::LoopControl(other_control)) => {
+                return self_control == other_control;
+            }
+            (Object::Function(self_fn), Object::Function(other_fn)) => {
+                return self_fn == other_fn;
+            }
+            (Object::String(self_string), Object::String(other_string)) => {
+                return self_string == other_string;
+            }
+            (Object::Array(self_array), Object::Array(other_array)) => {
+                return self_array == other_array;
+            }
+            (Object::Hash(self_hash), Object::Hash(other_hash)) => {
+                return self_hash == other_hash;
+            }
+            (Object::Builtin(self_builtin), Object::Builtin(other_builtin)) => {
+                return self_builtin == other_builtin;
+            }
             _ => {}
         }
         return false;
@@ -41,9 +88,45 @@ impl Object {
     pub fn string(&self) -> String {
         match self {
             Object::Integer(integer) => integer.string(),
+            Object::BigInteger(big_integer) => big_integer.string(),
+            Object::Float(float) => float.string(),
             Object::Bool(bool_object) => bool_object.string(),
             Object::Null(null_object) => null_object.string(),
             Object::ReturnValue(return_value) => return_value.string(),
+            Object::LoopControl(loop_control) => loop_control.string(),
+            Object::Function(function) => function.string(),
+            Object::String(string) => string.string(),
+            Object::Array(array) => array.string(),
+            Object::Hash(hash) => hash.string(),
+            Object::Builtin(builtin) => builtin.string(),
+        }
+    }
+
+    pub fn object_type(&self) -> ObjectType {
+        match self {
+            Object::Integer(_) => ObjectType::INTEGER,
+            Object::BigInteger(_) => ObjectType::INTEGER,
+            Object::Float(_) => ObjectType::FLOAT,
+            Object::Bool(_) => ObjectType::BOOLEAN,
+            Object::Null(_) => ObjectType::NULL,
+            Object::ReturnValue(_) => ObjectType::ReturnValue,
+            Object::LoopControl(_) => ObjectType::LoopControl,
+            Object::Function(_) => ObjectType::FUNCTION,
+            Object::String(_) => ObjectType::STRING,
+            Object::Array(_) => ObjectType::ARRAY,
+            Object::Hash(_) => ObjectType::HASH,
+            Object::Builtin(_) => ObjectType::BUILTIN,
+        }
+    }
+
+    // Only integers, booleans and strings can be used as hash keys.
+    pub fn hash_key(&self) -> Option<HashKey> {
+        match self {
+            Object::Integer(integer) => Some(integer.hash_key()),
+            Object::BigInteger(big_integer) => Some(big_integer.hash_key()),
+            Object::Bool(bool_object) => Some(bool_object.hash_key()),
+            Object::String(string) => Some(string.hash_key()),
+            _ => None,
         }
     }
 }
@@ -53,10 +136,22 @@ impl Object {
         Object::Integer(Integer::new(value))
     }
 
+    pub fn new_float(value: f64) -> Object {
+        Object::Float(Float::new(value))
+    }
+
     pub fn new_bool(value: bool)  -> Object {
         Object::Bool(Bool::new(value))
     }
 
+    pub fn new_string(value: &str) -> Object {
+        Object::String(Str::new(String::from(value)))
+    }
+
+    pub fn new_array(elements: Vec<Object>) -> Object {
+        Object::Array(Array::new(elements))
+    }
+
     pub fn null()  -> Object {
         Object::Null(NULL)
     }
@@ -67,4 +162,11 @@ impl Object {
             _ => false,
         }
     }
+
+    pub fn is_loop_control(&self) -> bool {
+        match self {
+            Object::LoopControl(_) => true,
+            _ => false,
+        }
+    }
 }
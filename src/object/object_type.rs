@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum ObjectType {
+    INTEGER,
+    FLOAT,
+    BOOLEAN,
+    NULL,
+    ReturnValue,
+    LoopControl,
+    FUNCTION,
+    STRING,
+    ARRAY,
+    HASH,
+    BUILTIN,
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ObjectType::INTEGER => write!(f, "INTEGER"),
+            ObjectType::FLOAT => write!(f, "FLOAT"),
+            ObjectType::BOOLEAN => write!(f, "BOOLEAN"),
+            ObjectType::NULL => write!(f, "NULL"),
+            ObjectType::ReturnValue => write!(f, "RETURN_VALUE"),
+            ObjectType::LoopControl => write!(f, "LOOP_CONTROL"),
+            ObjectType::FUNCTION => write!(f, "FUNCTION"),
+            ObjectType::STRING => write!(f, "STRING"),
+            ObjectType::ARRAY => write!(f, "ARRAY"),
+            ObjectType::HASH => write!(f, "HASH"),
+            ObjectType::BUILTIN => write!(f, "BUILTIN"),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use super::{
+    hash_key::HashKey, hashable_trait::Hashable, object_trait::ObjectTrait,
+    truthiness_trait::Truthiness,
+};
+
+#[derive(Clone, Debug, Eq)]
+pub struct Str {
+    pub value: String,
+}
+
+impl PartialEq for Str {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl ObjectTrait for Str {
+    fn string(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl Hashable for Str {
+    fn hash_key(&self) -> HashKey {
+        HashKey::String(self.value.clone())
+    }
+}
+
+impl Truthiness for Str {
+    fn is_truthy(&self) -> bool {
+        true
+    }
+}
+
+impl Str {
+    pub fn new(value: String) -> Str {
+        Str { value: value }
+    }
+}
@@ -1,5 +1,9 @@
+mod parse_array_literal;
+mod parse_assign_expression;
 mod parse_call_arguments;
 mod parse_call_expression;
+mod parse_hash_literal;
+mod parse_index_expression;
 mod parse_let_statement;
 mod parse_return_statement;
 pub mod parser;
@@ -0,0 +1,92 @@
+use super::parser::Parser;
+use crate::{
+    ast::{array_literal::ArrayLiteral, expression::Expression, operators::Operator},
+    token::TokenType,
+};
+
+impl<'a> Parser<'a> {
+    pub fn parse_array_literal(&mut self) -> Option<Expression> {
+        self.assert_current_token_type(TokenType::LBRACKET);
+        let token = self.current_token.clone().unwrap();
+        let elements = self.parse_expression_list(TokenType::RBRACKET)?;
+
+        Some(Expression::ArrayLiteral(ArrayLiteral {
+            token: token,
+            elements: elements,
+        }))
+    }
+
+    // Parses comma separated expressions up to and including the `end` token.
+    // Expects the current token to be the opening delimiter.
+    pub fn parse_expression_list(&mut self, end: TokenType) -> Option<Vec<Expression>> {
+        let mut list: Vec<Expression> = vec![];
+        if self.peek_token_is(end) {
+            self.next_token();
+            return Some(list);
+        }
+
+        self.next_token();
+        list.push(self.parse_expression(Operator::LOWEST)?);
+
+        while self.peek_token_is(TokenType::COMMA) {
+            self.next_token();
+            self.next_token();
+            list.push(self.parse_expression(Operator::LOWEST)?);
+        }
+
+        if !self.expect_peek(end) {
+            return None;
+        }
+        Some(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expression::Expression;
+    use crate::ast::statement::Statement;
+    use crate::ast::token_node::TokenNode;
+    use crate::lexer::Lexer;
+
+    use super::Parser;
+
+    #[test]
+    fn parse_array_literal() {
+        let tests = vec![
+            ("[1, 2 * 2, 3 + 3]", 3, "[1, (2 * 2), (3 + 3)]"),
+            ("[]", 0, "[]"),
+            ("[fn(x) { x }, [1]]", 2, "[fn (x) x, [1]]"),
+        ];
+        for (input, expected_length, expected_string) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(1, program.statements.len());
+
+            let array_literal = match program.statements[0].clone() {
+                Statement::ExpressionStatement(stmt) => match stmt.value {
+                    Some(Expression::ArrayLiteral(array_literal)) => Some(array_literal),
+                    _ => None,
+                },
+                _ => None,
+            };
+            assert!(array_literal.is_some(), "expected array literal");
+            let array_literal = array_literal.unwrap();
+            assert_eq!(*expected_length, array_literal.elements.len());
+            assert_eq!(*expected_string, array_literal.string());
+        }
+    }
+
+    #[test]
+    fn parse_unterminated_array_literal() {
+        let mut l = Lexer::new("[1, 2");
+        let mut p = Parser::new(&mut l);
+        p.parse_program();
+        assert!(!p.errors.is_empty());
+        assert_eq!(
+            "1:6: expected next token to be RBRACKET, got EOF instead",
+            p.errors[0]
+        );
+    }
+}
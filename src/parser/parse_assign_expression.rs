@@ -0,0 +1,112 @@
+use super::parser::Parser;
+use crate::{
+    ast::{assign_expression::AssignExpression, expression::Expression, operators::Operator},
+    token::TokenType,
+};
+
+impl<'a> Parser<'a> {
+    pub fn parse_assign_expression(&mut self, target: Expression) -> Option<Expression> {
+        self.next_token();
+        let token = self.current_token.clone().unwrap();
+
+        // Parsing the value at the lowest precedence makes assignment right
+        // associative: `a = b = c` is `a = (b = c)`.
+        self.next_token();
+        let value = self.parse_expression(Operator::LOWEST)?;
+        if !is_assignable(&target) {
+            self.errors.push(format!(
+                "{}: invalid assignment target: {}",
+                target.span(),
+                target.string()
+            ));
+            return None;
+        }
+
+        Some(Expression::AssignExpression(Box::new(AssignExpression {
+            token: token,
+            target: target,
+            value: value,
+        })))
+    }
+
+    pub fn is_assignment_token(&self, token_type: TokenType) -> bool {
+        match token_type {
+            TokenType::ASSIGN
+            | TokenType::PlusAssign
+            | TokenType::MinusAssign
+            | TokenType::AsteriskAssign
+            | TokenType::SlashAssign => true,
+            _ => false,
+        }
+    }
+}
+
+// Only a binding, or an element reached by indexing into a binding, can be
+// assigned to.
+fn is_assignable(target: &Expression) -> bool {
+    match target {
+        Expression::Identifier(_) => true,
+        Expression::IndexExpression(index_expression) => is_assignable(&index_expression.left),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expression::Expression;
+    use crate::ast::statement::Statement;
+    use crate::lexer::Lexer;
+
+    use super::Parser;
+
+    #[test]
+    fn parse_assign_expression() {
+        let tests = vec![
+            ("x = 5", "x", "=", "5"),
+            ("x += y * 2", "x", "+=", "(y * 2)"),
+            ("x -= 1", "x", "-=", "1"),
+            ("x *= 2", "x", "*=", "2"),
+            ("x /= 2", "x", "/=", "2"),
+            ("a[1] = 2", "(a[1])", "=", "2"),
+            ("h[\"k\"][0] += 1", "((h[\"k\"])[0])", "+=", "1"),
+            ("a = b = c", "a", "=", "(b = c)"),
+        ];
+        for (input, target, operator, value) in tests {
+            let mut l = Lexer::new(input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(1, program.statements.len());
+
+            let assign_expression = match program.statements[0].clone() {
+                Statement::ExpressionStatement(stmt) => match stmt.value {
+                    Some(Expression::AssignExpression(assign_expression)) => {
+                        Some(assign_expression)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            assert!(assign_expression.is_some(), "expected assign expression");
+            let assign_expression = assign_expression.unwrap();
+            assert_eq!(target, assign_expression.target.string());
+            assert_eq!(operator, assign_expression.token.literal);
+            assert_eq!(value, assign_expression.value.string());
+        }
+    }
+
+    #[test]
+    fn parse_invalid_assignment_target() {
+        let tests = vec![
+            ("5 = x", "1:1: invalid assignment target: 5"),
+            ("a + b = c", "1:1: invalid assignment target: (a + b)"),
+            ("f() += 1", "1:1: invalid assignment target: f()"),
+        ];
+        for (input, expected) in tests {
+            let mut l = Lexer::new(input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert_eq!(vec![String::from(expected)], p.errors);
+        }
+    }
+}
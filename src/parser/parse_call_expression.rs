@@ -2,10 +2,10 @@ use super::parser::Parser;
 use crate::{ast::call_expression::CallExpression, ast::expression::Expression};
 
 impl<'a> Parser<'a> {
-    pub fn parse_call_expression(&mut self, function: Expression) -> Expression {
+    pub fn parse_call_expression(&mut self, function: Expression) -> Option<Expression> {
         let token = self.current_token.clone().unwrap();
         self.next_token();
-        let arguments = self.parse_call_arguments();
+        let arguments = self.parse_call_arguments()?;
 
         let call_expression = CallExpression {
             token: token,
@@ -13,7 +13,7 @@ impl<'a> Parser<'a> {
             arguments: arguments,
         };
 
-        Expression::CallExpression(Box::new(call_expression))
+        Some(Expression::CallExpression(Box::new(call_expression)))
     }
 }
 
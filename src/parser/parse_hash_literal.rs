@@ -0,0 +1,110 @@
+use super::parser::Parser;
+use crate::{
+    ast::{expression::Expression, hash_literal::HashLiteral, operators::Operator},
+    token::TokenType,
+};
+
+impl<'a> Parser<'a> {
+    pub fn parse_hash_literal(&mut self) -> Option<Expression> {
+        self.assert_current_token_type(TokenType::LBRACE);
+        let token = self.current_token.clone().unwrap();
+        let mut pairs: Vec<(Expression, Expression)> = vec![];
+
+        while !self.peek_token_is(TokenType::RBRACE) {
+            self.next_token();
+            let key = self.parse_expression(Operator::LOWEST)?;
+            if !self.expect_peek(TokenType::COLON) {
+                return None;
+            }
+
+            self.next_token();
+            let value = self.parse_expression(Operator::LOWEST)?;
+            pairs.push((key, value));
+
+            if !self.peek_token_is(TokenType::RBRACE) && !self.expect_peek(TokenType::COMMA) {
+                return None;
+            }
+        }
+
+        if !self.expect_peek(TokenType::RBRACE) {
+            return None;
+        }
+        Some(Expression::HashLiteral(HashLiteral {
+            token: token,
+            pairs: pairs,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expression::Expression;
+    use crate::ast::statement::Statement;
+    use crate::lexer::Lexer;
+
+    use super::Parser;
+
+    #[test]
+    fn parse_hash_literal() {
+        let tests = vec![
+            (
+                r#"{"one": 1, "two": 2, "three": 3}"#,
+                vec![("\"one\"", "1"), ("\"two\"", "2"), ("\"three\"", "3")],
+            ),
+            ("{}", vec![]),
+            (
+                r#"{"one": 0 + 1, 2: 10 - 8, true: 15 / 5}"#,
+                vec![
+                    ("\"one\"", "(0 + 1)"),
+                    ("2", "(10 - 8)"),
+                    ("true", "(15 / 5)"),
+                ],
+            ),
+        ];
+        for (input, expected_pairs) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(1, program.statements.len());
+
+            let hash_literal = match program.statements[0].clone() {
+                Statement::ExpressionStatement(stmt) => match stmt.value {
+                    Some(Expression::HashLiteral(hash_literal)) => Some(hash_literal),
+                    _ => None,
+                },
+                _ => None,
+            };
+            assert!(hash_literal.is_some(), "expected hash literal");
+            let hash_literal = hash_literal.unwrap();
+            assert_eq!(expected_pairs.len(), hash_literal.pairs.len());
+            for ((key, value), (expected_key, expected_value)) in
+                hash_literal.pairs.iter().zip(expected_pairs.iter())
+            {
+                assert_eq!(*expected_key, key.string());
+                assert_eq!(*expected_value, value.string());
+            }
+        }
+    }
+
+    #[test]
+    fn parse_invalid_hash_literal() {
+        let tests = vec![
+            (
+                r#"{"one" 1}"#,
+                "1:8: expected next token to be COLON, got INT instead",
+            ),
+            (
+                r#"{"one": 1 "two": 2}"#,
+                "1:11: expected next token to be COMMA, got STRING instead",
+            ),
+        ];
+        for (input, expected_error) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert!(!p.errors.is_empty(), "{}", *input);
+            assert_eq!(*expected_error, p.errors[0], "{}", *input);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::parser::Parser;
+use crate::{
+    ast::{expression::Expression, index_expression::IndexExpression, operators::Operator},
+    token::TokenType,
+};
+
+impl<'a> Parser<'a> {
+    pub fn parse_index_expression(&mut self, left: Expression) -> Option<Expression> {
+        self.next_token();
+        self.assert_current_token_type(TokenType::LBRACKET);
+        let token = self.current_token.clone().unwrap();
+
+        self.next_token();
+        let index = self.parse_expression(Operator::LOWEST)?;
+        if !self.expect_peek(TokenType::RBRACKET) {
+            return None;
+        }
+
+        Some(Expression::IndexExpression(Box::new(IndexExpression {
+            token: token,
+            left: left,
+            index: index,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expression::Expression;
+    use crate::ast::statement::Statement;
+    use crate::lexer::Lexer;
+
+    use super::Parser;
+
+    #[test]
+    fn parse_index_expression() {
+        let input = "myArray[1 + 1]";
+        let mut l = Lexer::new(input);
+        let mut p = Parser::new(&mut l);
+        let program = p.parse_program();
+        assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+        assert_eq!(1, program.statements.len());
+
+        let index_expression = match program.statements[0].clone() {
+            Statement::ExpressionStatement(stmt) => match stmt.value {
+                Some(Expression::IndexExpression(index_expression)) => Some(index_expression),
+                _ => None,
+            },
+            _ => None,
+        };
+        assert!(index_expression.is_some(), "expected index expression");
+        let index_expression = index_expression.unwrap();
+        assert_eq!("myArray", index_expression.left.string());
+        assert_eq!("(1 + 1)", index_expression.index.string());
+    }
+}
@@ -10,6 +10,7 @@ impl<'a> Parser<'a> {
         if self.current_token.is_none() {
             return None;
         }
+        let let_span = self.current_token.clone().unwrap().span;
         if !self.expect_peek(TokenType::IDENT) {
             return None;
         }
@@ -18,6 +19,7 @@ impl<'a> Parser<'a> {
         let token = Token {
             token_type: t.token_type,
             literal: t.literal,
+            span: t.span,
         };
         let literal = String::from(token.literal.as_str());
         let identifier = Identifier {
@@ -28,6 +30,7 @@ impl<'a> Parser<'a> {
         let token2 = Token {
             token_type: t.token_type,
             literal: literal2,
+            span: let_span,
         };
 
         if !self.expect_peek(TokenType::ASSIGN) {
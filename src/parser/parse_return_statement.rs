@@ -13,6 +13,7 @@ impl<'a> Parser<'a> {
         let token = Token {
             token_type: t.token_type,
             literal: t.literal,
+            span: t.span,
         };
 
         self.next_token();
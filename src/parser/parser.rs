@@ -1,9 +1,13 @@
+use crate::ast::big_integer_literal::BigIntegerLiteral;
+use crate::ast::float_literal::FloatLiteral;
 use crate::ast::fn_literal::FnLiteral;
 use crate::ast::program::Program;
 use crate::ast::statement::Statement;
+use crate::ast::string_literal::StringLiteral;
 use crate::ast::{
-    block_statement::BlockStatement, if_expression::IfExpression,
-    operators::get_token_type_operator_precedence, trace::Tracer,
+    block_statement::BlockStatement, for_expression::ForExpression, if_expression::IfExpression,
+    loop_control_expression::LoopControlExpression, operators::get_token_type_operator_precedence,
+    trace::Tracer, while_expression::WhileExpression,
 };
 use crate::ast::{boolean_expression::BooleanExpression, identifier::Identifier};
 use crate::ast::{
@@ -12,6 +16,7 @@ use crate::ast::{
     operators::Operator, prefix_expression::PrefixExpression,
 };
 use crate::lexer::Lexer;
+use num_bigint::BigInt;
 use crate::token::{Token, TokenType};
 
 pub struct Parser<'a> {
@@ -20,6 +25,9 @@ pub struct Parser<'a> {
     pub current_token: Option<Token>,
     pub peek_token: Option<Token>,
     pub tracer: Tracer,
+    /// Whether integer literals too large for an `i64` parse as big integer
+    /// literals. When off they are reported as errors.
+    pub big_integers: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -30,6 +38,7 @@ impl<'a> Parser<'a> {
             current_token: None,
             peek_token: None,
             tracer: Tracer::new(true),
+            big_integers: false,
         };
         p.next_token();
         p.next_token();
@@ -46,7 +55,7 @@ impl<'a> Parser<'a> {
                 untrace(&mut self.tracer);
                 Some(identifier)
             }
-            TokenType::BANG | TokenType::MINUS => {
+            TokenType::BANG | TokenType::MINUS | TokenType::TILDE => {
                 let prefix_expression = self.parse_prefix_expression();
                 untrace(&mut self.tracer);
                 Some(prefix_expression)
@@ -55,14 +64,46 @@ impl<'a> Parser<'a> {
                 untrace(&mut self.tracer);
                 self.parse_if_expression()
             }
+            TokenType::WHILE => {
+                untrace(&mut self.tracer);
+                self.parse_while_expression()
+            }
+            TokenType::FOR => {
+                untrace(&mut self.tracer);
+                self.parse_for_expression()
+            }
+            TokenType::BREAK | TokenType::CONTINUE => {
+                untrace(&mut self.tracer);
+                Some(Expression::LoopControl(LoopControlExpression {
+                    token: self.current_token.clone().unwrap(),
+                }))
+            }
             TokenType::LPAREN => {
                 untrace(&mut self.tracer);
                 self.parse_grouped_expression()
             }
+            TokenType::LBRACKET => {
+                untrace(&mut self.tracer);
+                self.parse_array_literal()
+            }
+            TokenType::LBRACE => {
+                untrace(&mut self.tracer);
+                self.parse_hash_literal()
+            }
             TokenType::INT => {
                 let integer_expression = self.parse_integer();
                 untrace(&mut self.tracer);
-                Some(integer_expression)
+                integer_expression
+            }
+            TokenType::FLOAT => {
+                let float_expression = self.parse_float();
+                untrace(&mut self.tracer);
+                float_expression
+            }
+            TokenType::STRING => {
+                let string_expression = self.parse_string();
+                untrace(&mut self.tracer);
+                Some(string_expression)
             }
             TokenType::FUNCTION => {
                 let fn_expression = self.parse_fn_literal();
@@ -74,7 +115,17 @@ impl<'a> Parser<'a> {
                 untrace(&mut self.tracer);
                 Some(boolean_expression)
             }
+            // The lexer has already reported why an ILLEGAL token is illegal.
+            TokenType::ILLEGAL => {
+                untrace(&mut self.tracer);
+                None
+            }
             _ => {
+                let token = self.current_token.clone().unwrap();
+                self.errors.push(format!(
+                    "{}: no prefix parse function for {}",
+                    token.span, token_type
+                ));
                 untrace(&mut self.tracer);
                 None
             }
@@ -91,13 +142,54 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_integer(&self) -> Expression {
+    fn parse_integer(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone().unwrap();
+        if let Ok(literal) = token.literal.parse::<i64>() {
+            return Some(Expression::IntegerLiteral(IntegerLiteral {
+                token: token,
+                value: literal,
+            }));
+        }
+        if !self.big_integers {
+            self.errors.push(format!(
+                "{}: integer literal out of range: {}",
+                token.span, token.literal
+            ));
+            return None;
+        }
+        let value = token.literal.parse::<BigInt>().unwrap();
+        Some(Expression::BigIntegerLiteral(BigIntegerLiteral {
+            token: token,
+            value: value,
+        }))
+    }
+
+    // Infinity and NaN have no literal form, so a literal too large to
+    // represent is rejected rather than printed back as `inf`.
+    fn parse_float(&mut self) -> Option<Expression> {
         let token = self.current_token.clone().unwrap();
-        let literal = token.literal.parse::<i64>().unwrap();
+        let literal = token.literal.parse::<f64>().unwrap();
+        if !literal.is_finite() {
+            self.errors.push(format!(
+                "{}: float literal out of range: {}",
+                token.span, token.literal
+            ));
+            return None;
+        }
 
-        Expression::IntegerLiteral(IntegerLiteral {
+        Some(Expression::FloatLiteral(FloatLiteral {
             token: token,
             value: literal,
+        }))
+    }
+
+    fn parse_string(&self) -> Expression {
+        let token = self.current_token.clone().unwrap();
+        let value = token.literal.clone();
+
+        Expression::StringLiteral(StringLiteral {
+            token: token,
+            value: value,
         })
     }
 
@@ -150,7 +242,18 @@ impl<'a> Parser<'a> {
             | TokenType::EQ
             | TokenType::NotEq
             | TokenType::GT
-            | TokenType::LT => true,
+            | TokenType::LT
+            | TokenType::LtEq
+            | TokenType::GtEq
+            | TokenType::AND
+            | TokenType::OR
+            | TokenType::PERCENT
+            | TokenType::POWER
+            | TokenType::AMPERSAND
+            | TokenType::PIPE
+            | TokenType::CARET
+            | TokenType::ShiftLeft
+            | TokenType::ShiftRight => true,
             _ => false,
         }
     }
@@ -161,11 +264,11 @@ impl<'a> Parser<'a> {
                 .push(format!("expected current token {}, found none", tt));
             return false;
         }
-        let current_token_type = self.current_token.clone().unwrap().token_type;
-        if current_token_type != tt {
+        let current_token = self.current_token.clone().unwrap();
+        if current_token.token_type != tt {
             self.errors.push(format!(
-                "expected current token {}, found {}",
-                tt, current_token_type
+                "{}: expected current token {}, found {}",
+                current_token.span, tt, current_token.token_type
             ));
             return false;
         }
@@ -254,6 +357,10 @@ impl<'a> Parser<'a> {
         }
 
         let body = self.parse_block_statement();
+        if body.is_none() {
+            untrace(&mut self.tracer);
+            return None;
+        }
 
         let expression = FnLiteral {
             token: fn_token,
@@ -295,18 +402,41 @@ impl<'a> Parser<'a> {
         }
         let consequence = self.parse_block_statement();
         if consequence.is_none() {
-            self.next_token();
             untrace(&mut self.tracer);
-            panic!("no consequence provided");
+            return None;
         }
 
         let mut alternative = None;
-        if self.current_token_is(TokenType::ELSE) {
+        if self.peek_token_is(TokenType::ELSE) {
             self.next_token();
-            self.assert_current_token_type(TokenType::LBRACE);
-
-            let alternative_block = self.parse_block_statement();
-            if alternative_block.is_some() {
+            if self.peek_token_is(TokenType::IF) {
+                // `else if` is sugar for an `else` block holding just the
+                // nested if expression.
+                self.next_token();
+                let else_if_token = self.current_token.clone().unwrap();
+                let else_if = self.parse_if_expression();
+                if else_if.is_none() {
+                    untrace(&mut self.tracer);
+                    return None;
+                }
+                alternative = Some(Box::new(BlockStatement {
+                    token: else_if_token.clone(),
+                    statements: vec![Statement::ExpressionStatement(ExpressionStatement {
+                        token: else_if_token,
+                        value: else_if,
+                    })],
+                }));
+            } else {
+                if !self.expect_peek(TokenType::LBRACE) {
+                    self.next_token();
+                    untrace(&mut self.tracer);
+                    return None;
+                }
+                let alternative_block = self.parse_block_statement();
+                if alternative_block.is_none() {
+                    untrace(&mut self.tracer);
+                    return None;
+                }
                 alternative = Some(Box::new(alternative_block.unwrap()));
             }
         }
@@ -322,6 +452,110 @@ impl<'a> Parser<'a> {
         Some(Expression::IfExpression(expression))
     }
 
+    fn parse_while_expression(&mut self) -> Option<Expression> {
+        let s = format!("parse_while_expression");
+        let untrace = self.tracer.trace(s.as_str());
+
+        let while_token = self.current_token.clone().unwrap();
+
+        if !self.expect_peek(TokenType::LPAREN) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        self.next_token();
+        let condition = self.parse_expression(Operator::LOWEST);
+        if condition.is_none() {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        if !self.expect_peek(TokenType::RPAREN) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        if !self.expect_peek(TokenType::LBRACE) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        let body = self.parse_block_statement();
+        if body.is_none() {
+            untrace(&mut self.tracer);
+            return None;
+        }
+
+        let expression = WhileExpression {
+            token: while_token,
+            condition: Box::new(condition.unwrap()),
+            body: Box::new(body.unwrap()),
+        };
+
+        untrace(&mut self.tracer);
+        Some(Expression::WhileExpression(expression))
+    }
+
+    fn parse_for_expression(&mut self) -> Option<Expression> {
+        let s = format!("parse_for_expression");
+        let untrace = self.tracer.trace(s.as_str());
+
+        let for_token = self.current_token.clone().unwrap();
+
+        if !self.expect_peek(TokenType::LPAREN) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        if !self.expect_peek(TokenType::IDENT) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        let variable_token = self.current_token.clone().unwrap();
+        let variable = Identifier {
+            value: variable_token.literal.clone(),
+            token: variable_token,
+        };
+        if !self.expect_peek(TokenType::IN) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        self.next_token();
+        let iterable = self.parse_expression(Operator::LOWEST);
+        if iterable.is_none() {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        if !self.expect_peek(TokenType::RPAREN) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        if !self.expect_peek(TokenType::LBRACE) {
+            self.next_token();
+            untrace(&mut self.tracer);
+            return None;
+        }
+        let body = self.parse_block_statement();
+        if body.is_none() {
+            untrace(&mut self.tracer);
+            return None;
+        }
+
+        let expression = ForExpression {
+            token: for_token,
+            variable: variable,
+            iterable: Box::new(iterable.unwrap()),
+            body: Box::new(body.unwrap()),
+        };
+
+        untrace(&mut self.tracer);
+        Some(Expression::ForExpression(expression))
+    }
+
     fn parse_block_statement(&mut self) -> Option<BlockStatement> {
         let s = format!("parse_block_expression");
         let untrace = self.tracer.trace(s.as_str());
@@ -334,12 +568,22 @@ impl<'a> Parser<'a> {
         let token = self.current_token.clone();
         self.next_token();
         while !self.current_token_is(TokenType::RBRACE) {
+            if self.current_token_is(TokenType::EOF) {
+                let eof_token = self.current_token.clone().unwrap();
+                self.errors.push(format!(
+                    "{}: expected next token to be {}, got {} instead",
+                    eof_token.span,
+                    TokenType::RBRACE,
+                    TokenType::EOF
+                ));
+                untrace(&mut self.tracer);
+                return None;
+            }
             let statement = self.parse_statement();
             if statement.is_some() {
                 statements.push(statement.unwrap());
             }
         }
-        self.next_token();
 
         let block_statement = BlockStatement {
             token: token.unwrap(),
@@ -357,7 +601,11 @@ impl<'a> Parser<'a> {
         let untrace = self.tracer.trace(s.as_str());
         let token = self.current_token.clone().unwrap();
         let operator = token.clone().literal;
-        let precedence = self.current_precedence();
+        let mut precedence = self.current_precedence();
+        // `**` is right associative: `a ** b ** c` is `a ** (b ** c)`.
+        if precedence == Operator::POWER {
+            precedence = Operator::PREFIX;
+        }
         self.next_token();
 
         let right = self.parse_expression(precedence);
@@ -378,6 +626,7 @@ impl<'a> Parser<'a> {
     pub fn next_token(&mut self) {
         self.current_token = self.peek_token.clone();
         self.peek_token = Some(self.l.next_token());
+        self.errors.append(&mut self.l.errors);
     }
 
     pub fn peek_token_is(&self, tt: TokenType) -> bool {
@@ -398,10 +647,10 @@ impl<'a> Parser<'a> {
             )));
             false
         } else {
+            let peek_token = self.peek_token.clone().unwrap();
             self.errors.push(String::from(format!(
-                "expected next token to be {}, got {} instead",
-                tt,
-                self.peek_token.clone().unwrap().token_type
+                "{}: expected next token to be {}, got {} instead",
+                peek_token.span, tt, peek_token.token_type
             )));
             false
         }
@@ -443,23 +692,43 @@ impl<'a> Parser<'a> {
             untrace(&mut self.tracer);
             return None;
         }
-        let prefix = self.parse_prefix(self.current_token.clone().unwrap().token_type);
+        let token_type = self.current_token.clone().unwrap().token_type;
+        let prefix = self.parse_prefix(token_type);
         if prefix.is_none() {
-            self.errors.push(format!(
-                "no prefix parse function for {}",
-                self.current_token.clone().unwrap().token_type
-            ));
             untrace(&mut self.tracer);
             return None;
         }
         let mut left = prefix.unwrap();
         while !self.peek_token_is(TokenType::SEMICOLON) && operator < self.peek_precedence() {
-            let is_infix_token = self.is_infix_token(self.peek_token.clone().unwrap().token_type);
+            let peek_token_type = self.peek_token.clone().unwrap().token_type;
+            let is_infix_token = self.is_infix_token(peek_token_type);
             if is_infix_token {
                 self.next_token();
                 left = Expression::InfixExpression(self.parse_infix_expression(left));
             } else if self.peek_token_is(TokenType::LPAREN) {
-                left = self.parse_call_expression(left);
+                match self.parse_call_expression(left) {
+                    Some(call_expression) => left = call_expression,
+                    None => {
+                        untrace(&mut self.tracer);
+                        return None;
+                    }
+                }
+            } else if self.peek_token_is(TokenType::LBRACKET) {
+                match self.parse_index_expression(left) {
+                    Some(index_expression) => left = index_expression,
+                    None => {
+                        untrace(&mut self.tracer);
+                        return None;
+                    }
+                }
+            } else if self.is_assignment_token(peek_token_type) {
+                match self.parse_assign_expression(left) {
+                    Some(assign_expression) => left = assign_expression,
+                    None => {
+                        untrace(&mut self.tracer);
+                        return None;
+                    }
+                }
             } else {
                 untrace(&mut self.tracer);
                 return Some(left);
@@ -482,13 +751,10 @@ impl<'a> Parser<'a> {
             token: t,
             value: expression,
         };
-        self.next_token();
-        if !self.current_token_is(TokenType::RBRACE) {
-            if !self.current_token_is(TokenType::SEMICOLON) {
-                self.next_token();
-            }
+        if self.peek_token_is(TokenType::SEMICOLON) {
             self.next_token();
         }
+        self.next_token();
         Some(expression_statement)
     }
 
@@ -673,6 +939,247 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn else_if_chains() {
+        let tests = vec![
+            ("if (a) { 1 } else { 2 }", "if a 1 else 2"),
+            ("if (a) { 1 } else if (b) { 2 }", "if a 1 else if b 2"),
+            (
+                "if (a) { 1 } else if (b) { 2 } else if (c) { 3 } else { 4 }",
+                "if a 1 else if b 2 else if c 3 else 4",
+            ),
+        ];
+        for (input, expected) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(1, program.statements.len());
+            assert_eq!(*expected, program.string(), "{}", *input);
+        }
+    }
+
+    #[test]
+    fn else_if_string() {
+        let mut l = Lexer::new("if (a) { 1 } else if (b) { 2 } else { 3 }");
+        let mut p = Parser::new(&mut l);
+        let program = p.parse_program();
+        assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+        let expression = match program.statements[0].clone() {
+            Statement::ExpressionStatement(st) => st.value.unwrap(),
+            _ => panic!("expected expression statement"),
+        };
+        let if_expression = match &expression {
+            Expression::IfExpression(if_expression) => if_expression,
+            _ => panic!("expected if expression"),
+        };
+        assert!(if_expression.else_if().is_some());
+        assert_eq!("if a 1 else if b 2 else 3", expression.string());
+    }
+
+    #[test]
+    fn failed_else_if_reports_one_error() {
+        let mut l = Lexer::new("if (false) { 1 } else if");
+        let mut p = Parser::new(&mut l);
+        p.parse_program();
+        assert_eq!(
+            vec!["1:25: expected next token to be LPAREN, got EOF instead"],
+            p.errors
+        );
+    }
+
+    #[test]
+    fn else_requires_block_or_if() {
+        let mut l = Lexer::new("if (a) { 1 } else 2");
+        let mut p = Parser::new(&mut l);
+        p.parse_program();
+        assert_eq!(
+            "1:19: expected next token to be LBRACE, got INT instead",
+            p.errors[0]
+        );
+    }
+
+    #[test]
+    fn loop_expressions() {
+        let tests = vec![
+            ("while (i < 3) { i += 1 }", "while (i < 3) (i += 1)"),
+            ("for (x in xs) { total += x; }", "for x in xs (total += x)"),
+            (
+                "for (c in \"ab\") { if (c == \"b\") { break } continue }",
+                "for c in \"ab\" if (c == \"b\") breakcontinue",
+            ),
+        ];
+        for (input, expected) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(1, program.statements.len());
+            assert_eq!(*expected, program.string(), "{}", *input);
+        }
+    }
+
+    #[test]
+    fn malformed_loops_are_reported() {
+        let tests = vec![
+            (
+                "while i < 3 { i }",
+                "1:7: expected next token to be LPAREN, got IDENT instead",
+            ),
+            (
+                "for (1 in xs) { }",
+                "1:6: expected next token to be IDENT, got INT instead",
+            ),
+            (
+                "for (x of xs) { }",
+                "1:8: expected next token to be IN, got IDENT instead",
+            ),
+        ];
+        for (input, expected_error) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert!(!p.errors.is_empty(), "{}", *input);
+            assert_eq!(*expected_error, p.errors[0], "{}", *input);
+        }
+    }
+
+    #[test]
+    fn unclosed_blocks_are_reported() {
+        let tests = vec![
+            (
+                "if (true) { 1",
+                vec!["1:14: expected next token to be RBRACE, got EOF instead"],
+            ),
+            (
+                "if (true) { 1 } else { 2",
+                vec!["1:25: expected next token to be RBRACE, got EOF instead"],
+            ),
+            (
+                "fn(x) { x",
+                vec!["1:10: expected next token to be RBRACE, got EOF instead"],
+            ),
+            (
+                "while (true) { if (x) { 1 }",
+                vec!["1:28: expected next token to be RBRACE, got EOF instead"],
+            ),
+        ];
+        for (input, expected_errors) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert_eq!(*expected_errors, p.errors, "{}", *input);
+        }
+    }
+
+    #[test]
+    fn string_literal_expression() {
+        let input = r#""hello\tworld";"#;
+        let mut l = Lexer::new(input);
+        let mut p = Parser::new(&mut l);
+        let program = p.parse_program();
+        assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+        assert_eq!(1, program.statements.len());
+        let statement = program.statements[0].clone();
+        match statement {
+            Statement::ExpressionStatement(st) => match st.value.unwrap() {
+                Expression::StringLiteral(string_literal) => {
+                    assert_eq!("hello\tworld", string_literal.value);
+                    assert_eq!(r#""hello\tworld""#, string_literal.string());
+                }
+                _ => assert!(false, "expected string literal"),
+            },
+            _ => {
+                assert!(false, "expected expression statement");
+            }
+        }
+    }
+
+    #[test]
+    fn errors_report_line_and_column() {
+        let tests = vec![
+            (
+                "let a = 1;\nlet = 2;",
+                "2:5: expected next token to be IDENT, got ASSIGN instead",
+            ),
+            ("let a = 1;\n\n  a + ;", "3:7: no prefix parse function for SEMICOLON"),
+        ];
+        for (input, expected_error) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert!(!p.errors.is_empty(), "{}", *input);
+            assert_eq!(*expected_error, p.errors[0], "{}", *input);
+        }
+    }
+
+    #[test]
+    fn illegal_tokens_are_reported() {
+        let tests = vec![
+            ("let x = 1 @ 2; x", vec!["1:11: illegal character '@'"]),
+            ("a # b; c", vec!["1:3: illegal character '#'"]),
+            (r#"let s = "abc"#, vec!["1:9: unterminated string"]),
+            (
+                "[@]",
+                vec![
+                    "1:2: illegal character '@'",
+                    "1:3: no prefix parse function for RBRACKET",
+                ],
+            ),
+            (
+                "f(@)",
+                vec![
+                    "1:3: illegal character '@'",
+                    "1:4: no prefix parse function for RPAREN",
+                ],
+            ),
+            (
+                "puts(1, $)",
+                vec![
+                    "1:9: illegal character '$'",
+                    "1:10: no prefix parse function for RPAREN",
+                ],
+            ),
+            (r#"f("abc)"#, vec!["1:3: unterminated string"]),
+            (
+                "f(1 +)",
+                vec![
+                    "1:6: no prefix parse function for RPAREN",
+                    "1:7: expected next token to be RPAREN, got EOF instead",
+                ],
+            ),
+        ];
+        for (input, expected_errors) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            p.parse_program();
+            assert_eq!(*expected_errors, p.errors, "{}", *input);
+        }
+    }
+
+    #[test]
+    fn float_literal_expression() {
+        let input = "2.5e-3;";
+        let mut l = Lexer::new(input);
+        let mut p = Parser::new(&mut l);
+        let program = p.parse_program();
+        assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+        assert_eq!(1, program.statements.len());
+        let statement = program.statements[0].clone();
+        match statement {
+            Statement::ExpressionStatement(st) => match st.value.unwrap() {
+                Expression::FloatLiteral(float_literal) => {
+                    assert_eq!(2.5e-3, float_literal.value);
+                    assert_eq!("2.5e-3", float_literal.string());
+                }
+                _ => assert!(false, "expected float literal"),
+            },
+            _ => {
+                assert!(false, "expected expression statement");
+            }
+        }
+    }
+
     #[test]
     fn integer_boolean_expression() {
         let tests = vec![("true;", true), ("false;", false)];
@@ -855,6 +1362,26 @@ pub mod tests {
             ("3 + 4; -5 * 5", "(3 + 4)((-5) * 5)"),
             ("5 > 4 == 3 < 4", "((5 > 4) == (3 < 4))"),
             ("5 < 4 != 3 > 4", "((5 < 4) != (3 > 4))"),
+            ("5 <= 4 == 3 >= 4", "((5 <= 4) == (3 >= 4))"),
+            ("a || b && c", "(a || (b && c))"),
+            ("a && b || c && d", "((a && b) || (c && d))"),
+            ("a == b && c != d", "((a == b) && (c != d))"),
+            ("a < b || !c", "((a < b) || (!c))"),
+            ("a || b || c", "((a || b) || c)"),
+            ("a % b * c", "((a % b) * c)"),
+            ("a + b % c", "(a + (b % c))"),
+            ("a ** b ** c", "(a ** (b ** c))"),
+            ("x = a || b", "(x = (a || b))"),
+            ("x += y = z * 2", "(x += (y = (z * 2)))"),
+            ("a[i] = b[j] + 1", "((a[i]) = ((b[j]) + 1))"),
+            ("-a ** b", "(-(a ** b))"),
+            ("a ** -b", "(a ** (-b))"),
+            ("a * b ** c", "(a * (b ** c))"),
+            ("a | b ^ c & d", "(a | (b ^ (c & d)))"),
+            ("a & b << c + d", "(a & (b << (c + d)))"),
+            ("a >> b == c | d", "((a >> b) == (c | d))"),
+            ("~a & b", "((~a) & b)"),
+            ("a < b | c && d", "((a < (b | c)) && d)"),
             (
                 "3 + 4 * 5 == 3 * 1 + 4 * 5",
                 "((3 + (4 * 5)) == ((3 * 1) + (4 * 5)))",
@@ -878,6 +1405,16 @@ pub mod tests {
                 "add(a + b + c * d / f + g)",
                 "add((((a + b) + ((c * d) / f)) + g))",
             ),
+            (
+                "a * [1, 2, 3, 4][b * c] * d",
+                "((a * ([1, 2, 3, 4][(b * c)])) * d)",
+            ),
+            (
+                "add(a * b[2], b[1], 2 * [1, 2][1])",
+                "add((a * (b[2])), (b[1]), (2 * ([1, 2][1])))",
+            ),
+            ("f(x)[0]", "(f(x)[0])"),
+            ("-a[0]", "(-(a[0]))"),
         ];
         for (input, expected_output) in tests.iter() {
             let mut l = Lexer::new(*input);
@@ -888,6 +1425,27 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn statements_after_block_expression() {
+        let tests: Vec<(&str, usize)> = vec![
+            ("if (a) { b } c", 2),
+            ("if (a) { if (b) { return c; } return d; }", 1),
+            ("if (a) { b } else { c }; d; e", 3),
+            ("fn(x) { x }(1); y", 2),
+        ];
+        for (input, expected_statements) in tests.iter() {
+            let mut l = Lexer::new(*input);
+            let mut p = Parser::new(&mut l);
+            let program = p.parse_program();
+            assert_eq!(0, p.errors.len(), "{}", p.errors.join(", "));
+            assert_eq!(*expected_statements, program.statements.len(), "{}", *input);
+        }
+        let mut l = Lexer::new("if (a) { if (b) { return c; } return d; }");
+        let mut p = Parser::new(&mut l);
+        let program = p.parse_program();
+        assert_eq!("if a if b RETURN c;RETURN d;", program.string().as_str());
+    }
+
     #[test]
     fn fn_literal_parsing() {
         let input = "fn(x, y) { x + y; }";
@@ -1,4 +1,4 @@
-use interpreter::evaluator::evaluate::evaluate;
+use interpreter::interpreter::Interpreter;
 use std::io::{self, Write};
 use users::{get_current_uid, get_user_by_uid};
 
@@ -31,6 +31,8 @@ pub fn start() {
     let mut handle = stdout.lock();
     handle.write_all(String::from(welcome).as_bytes()).unwrap();
 
+    let mut interpreter = Interpreter::new();
+
     loop {
         handle.write_all(PROMPT).unwrap();
         handle.flush().unwrap();
@@ -41,14 +43,17 @@ pub fn start() {
                 if input.trim().eq("quit") {
                     return;
                 }
-                let result = evaluate(input.as_str());
+                let result = interpreter.eval(input.as_str());
                 match result {
                     Ok(object) => {
                         println!("{}", object.string())
                     }
-                    Err(e) => {
-                        println!("{}\n{}", MONKEY_FACE, e);
-                    }
+                    Err(e) => match e.node() {
+                        Some(node) => {
+                            println!("{}\n{}\n    in: {}", MONKEY_FACE, e, node.string())
+                        }
+                        None => println!("{}\n{}", MONKEY_FACE, e),
+                    },
                 }
             }
             Err(error) => {
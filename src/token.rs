@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, rc::Rc};
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum TokenType {
@@ -11,20 +11,42 @@ pub enum TokenType {
     BANG,
     ASTERISK,
     SLASH,
+    PERCENT,
+    AMPERSAND,
+    PIPE,
+    CARET,
+    TILDE,
     LT,
     GT,
     COMMA,
+    COLON,
     SEMICOLON,
     LPAREN,
     RPAREN,
     LBRACE,
     RBRACE,
+    LBRACKET,
+    RBRACKET,
 
     EQ,
     NotEq,
+    LtEq,
+    GtEq,
+    AND,
+    OR,
+    POWER,
+    ShiftLeft,
+    ShiftRight,
+    PlusAssign,
+    MinusAssign,
+    AsteriskAssign,
+    SlashAssign,
 
     IDENT,
     INT,
+    FLOAT,
+    STRING,
+    COMMENT,
 
     LET,
     FUNCTION,
@@ -33,6 +55,11 @@ pub enum TokenType {
     RETURN,
     TRUE,
     FALSE,
+    WHILE,
+    FOR,
+    IN,
+    BREAK,
+    CONTINUE,
 }
 
 impl Default for TokenType {
@@ -47,10 +74,45 @@ impl fmt::Display for TokenType {
     }
 }
 
+// A region of the source: the byte range `start..end` together with the
+// 1-based line and column of its first character. `file` is the name the
+// source was loaded from, if any.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub file: Option<Rc<str>>,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    // The span running from the start of `self` to the end of `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            file: self.file.clone(),
+            start: self.start,
+            end: self.end.max(other.end),
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Token {
     pub literal: String,
     pub token_type: TokenType,
+    pub span: Span,
 }
 
 impl fmt::Display for Token {
@@ -75,12 +137,20 @@ pub fn lookup_char(ch: Option<char>) -> Option<TokenType> {
         ')' => Some(TokenType::RPAREN),
         '{' => Some(TokenType::LBRACE),
         '}' => Some(TokenType::RBRACE),
+        '[' => Some(TokenType::LBRACKET),
+        ']' => Some(TokenType::RBRACKET),
         ',' => Some(TokenType::COMMA),
+        ':' => Some(TokenType::COLON),
         '+' => Some(TokenType::PLUS),
         '-' => Some(TokenType::MINUS),
         '!' => Some(TokenType::BANG),
         '*' => Some(TokenType::ASTERISK),
         '/' => Some(TokenType::SLASH),
+        '%' => Some(TokenType::PERCENT),
+        '&' => Some(TokenType::AMPERSAND),
+        '|' => Some(TokenType::PIPE),
+        '^' => Some(TokenType::CARET),
+        '~' => Some(TokenType::TILDE),
         '<' => Some(TokenType::LT),
         '>' => Some(TokenType::GT),
         _ => None,
@@ -96,6 +166,11 @@ pub fn lookup_keyword(literal: &str) -> TokenType {
         "return" => TokenType::RETURN,
         "true" => TokenType::TRUE,
         "false" => TokenType::FALSE,
+        "while" => TokenType::WHILE,
+        "for" => TokenType::FOR,
+        "in" => TokenType::IN,
+        "break" => TokenType::BREAK,
+        "continue" => TokenType::CONTINUE,
         _ => TokenType::IDENT,
     }
 }
@@ -108,6 +183,9 @@ mod tests {
     fn get_keyword() {
         assert_eq!(TokenType::LET, lookup_keyword("let"));
         assert_eq!(TokenType::FUNCTION, lookup_keyword("fn"));
+        assert_eq!(TokenType::WHILE, lookup_keyword("while"));
+        assert_eq!(TokenType::IN, lookup_keyword("in"));
+        assert_eq!(TokenType::CONTINUE, lookup_keyword("continue"));
         assert_eq!(TokenType::IDENT, lookup_keyword("blah"));
     }
 }
@@ -0,0 +1,129 @@
+use interpreter::evaluator::{
+    config::{Config, IndexPolicy},
+    evaluate::{evaluate, evaluate_with_config},
+};
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn array_literal() {
+    let obj = match evaluate("[1, 2 * 2, 3 + 3]") {
+        Ok(object) => object,
+        Err(e) => panic!("{}", e),
+    };
+    let array = match obj {
+        Object::Array(array) => array,
+        _ => panic!("expected array object, got {}", obj.string()),
+    };
+    assert_eq!(3, array.elements.len());
+    shared::test_integer_object(array.elements[0].clone(), 1);
+    shared::test_integer_object(array.elements[1].clone(), 4);
+    shared::test_integer_object(array.elements[2].clone(), 6);
+}
+
+#[test]
+fn array_index_expression() {
+    let tests = vec![
+        ("[1, 2, 3][0]", 1),
+        ("[1, 2, 3][1]", 2),
+        ("[1, 2, 3][2]", 3),
+        ("let i = 0; [1][i];", 1),
+        ("[1, 2, 3][1 + 1];", 3),
+        ("let myArray = [1, 2, 3]; myArray[2];", 3),
+        (
+            "let myArray = [1, 2, 3]; myArray[0] + myArray[1] + myArray[2];",
+            6,
+        ),
+        ("let myArray = [1, 2, 3]; let i = myArray[0]; myArray[i]", 2),
+        ("[fn(x) { x * 2 }][0](4)", 8),
+        ("[[1, 2], [3, 4]][1][0]", 3),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn array_index_out_of_bounds() {
+    for input in ["[1, 2, 3][3]", "[1, 2, 3][-1]", "[][0]"].iter() {
+        match evaluate(*input) {
+            Ok(object) => assert_eq!(Object::null(), object, "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+
+    let config = Config::new().with_index_policy(IndexPolicy::Error);
+    let tests = vec![
+        (
+            "[1, 2, 3][3]",
+            "1:1: index out of bounds: index 3, length 3",
+            "([1, 2, 3][3])",
+        ),
+        (
+            "[1, 2, 3][-1]",
+            "1:1: index out of bounds: index -1, length 3",
+            "([1, 2, 3][(-1)])",
+        ),
+    ];
+    for (input, expected_error, expected_node) in tests.iter() {
+        match evaluate_with_config(*input, &config) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input);
+                assert_eq!(*expected_node, e.node().unwrap().string(), "{}", *input);
+            }
+        }
+    }
+}
+
+#[test]
+fn array_index_errors() {
+    let tests = vec![
+        (
+            "1[0]",
+            "1:1: index operator not supported: INTEGER[INTEGER]",
+        ),
+        (
+            "[1, 2][true]",
+            "1:1: index operator not supported: ARRAY[BOOLEAN]",
+        ),
+        ("[1, 2][foo]", "1:8: identifier not found: foo"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn array_equality() {
+    let tests = vec![
+        ("[1, 2] == [1, 2]", true),
+        ("[1, [2, 3]] == [1, [2, 3]]", true),
+        ("[1, 2] == [2, 1]", false),
+        ("[1, 2] != [1, 2, 3]", true),
+        ("[] == []", true),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        shared::test_bool_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn array_string() {
+    match evaluate("[1, true, [2]]") {
+        Ok(object) => assert_eq!("[1, true, [2]]", object.string()),
+        Err(e) => panic!("{}", e),
+    }
+}
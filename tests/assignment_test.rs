@@ -0,0 +1,187 @@
+use std::time::{Duration, Instant};
+
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn assignment() {
+    let tests = vec![
+        ("let x = 1; x = 2; x", 2),
+        ("let x = 1; x = x + 4", 5),
+        ("let x = 10; x += 5; x", 15),
+        ("let x = 10; x -= 5; x", 5),
+        ("let x = 10; x *= 5; x", 50),
+        ("let x = 10; x /= 5; x", 2),
+        ("let x = 3; x *= 2 + 1", 9),
+        ("let a = 1; let b = 2; a = b = 7; a + b", 14),
+        ("let x = 1; let y = (x += 1) * 10; x + y", 22),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn assignment_updates_nearest_binding() {
+    let tests = vec![
+        (
+            "let count = 0; let inc = fn() { count += 1 }; inc(); inc(); count",
+            2,
+        ),
+        (
+            "let x = 1; let shadow = fn() { let x = 5; x = 10; x }; shadow() + x",
+            11,
+        ),
+        (
+            "let counter = fn() { let n = 0; fn() { n += 1 } }; let c = counter(); c(); c(); c()",
+            3,
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn index_assignment() {
+    let tests = vec![
+        ("let a = [1, 2, 3]; a[1] = 5; a", "[1, 5, 3]"),
+        ("let a = [1, 2, 3]; a[2] += 10; a", "[1, 2, 13]"),
+        ("let a = [1, 2, 3]; a[0] = 9", "9"),
+        ("let a = [1, 2]; let b = a; a[0] = 7; b", "[1, 2]"),
+        ("let h = {\"a\": 1}; h[\"a\"] = 2; h", "{a: 2}"),
+        ("let h = {\"a\": 1}; h[\"b\"] = 2; h", "{a: 1, b: 2}"),
+        (
+            "let m = [[1, 2], [3, 4]]; m[1][0] *= 10; m",
+            "[[1, 2], [30, 4]]",
+        ),
+        ("let h = {\"xs\": [1]}; h[\"xs\"][0] -= 1; h", "{xs: [0]}"),
+        (
+            "let a = [0]; let set = fn(v) { a[0] = v }; set(4); a",
+            "[4]",
+        ),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => assert_eq!(*expected, object.string(), "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn assignment_errors() {
+    let tests = vec![
+        ("x = 1", "1:1: assignment to undeclared variable: x"),
+        (
+            "let f = fn() { y += 1 }; f()",
+            "1:16: assignment to undeclared variable: y",
+        ),
+        (
+            "let a = [1]; a[1] = 2",
+            "1:14: index out of bounds: index 1, length 1",
+        ),
+        (
+            "let a = [1]; a[-1] = 2",
+            "1:14: index out of bounds: index -1, length 1",
+        ),
+        (
+            "let a = 1; a[0] = 2",
+            "1:12: index operator not supported: INTEGER[INTEGER]",
+        ),
+        (
+            "let h = {}; h[fn(x) { x }] = 1",
+            "1:15: unusable as hash key: FUNCTION",
+        ),
+        ("let x = 1; x /= 0", "1:12: division by zero"),
+        (
+            "let s = \"a\"; s -= 1",
+            "1:14: type mismatch: STRING - INTEGER",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn invalid_assignment_target() {
+    let tests = vec![
+        ("1 = 2", "1:1: invalid assignment target: 1"),
+        (
+            "let a = 1; a + 1 = 2",
+            "1:12: invalid assignment target: (a + 1)",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert!(e.to_string().contains(*expected_error), "{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn failed_index_assignment_changes_nothing() {
+    let mut interpreter = Interpreter::new();
+    let tests = vec![
+        (
+            "let a = [1, [2]]; a[1][0] += true",
+            "1:19: type mismatch: INTEGER + BOOLEAN",
+        ),
+        (
+            "let h = {\"a\": 1}; h[\"b\"][0] = 1",
+            "1:19: index operator not supported: NULL[INTEGER]",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+    match interpreter.eval("[a, h]") {
+        Ok(object) => assert_eq!("[[1, [2]], {a: 1}]", object.string()),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn time_to_assign(length: usize) -> Duration {
+    let mut interpreter = Interpreter::new();
+    interpreter.set("a", Object::new_array(vec![Object::new_integer(0); length]));
+    let input = format!("let i = 0; while (i < {}) {{ a[i] = i; i += 1 }}", length);
+    let start = Instant::now();
+    if let Err(e) = interpreter.eval(input.as_str()) {
+        panic!("{}", e);
+    }
+    start.elapsed()
+}
+
+// Assigning an element updates the array in place; copying the array on each
+// assignment would make the loop quadratic, taking ~64 times longer on an
+// array 8 times larger instead of ~8 times.
+#[test]
+fn index_assignment_is_linear_in_array_size() {
+    time_to_assign(1000);
+    let small_time = time_to_assign(2000);
+    let large_time = time_to_assign(16000);
+    let ratio = large_time.as_secs_f64() / small_time.as_secs_f64();
+    assert!(
+        ratio < 24.0,
+        "assigning 8x the elements took {:.1}x as long ({:?} vs {:?})",
+        ratio,
+        large_time,
+        small_time
+    );
+}
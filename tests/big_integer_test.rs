@@ -0,0 +1,206 @@
+use interpreter::evaluator::config::{Config, IndexPolicy, OverflowPolicy};
+use interpreter::evaluator::evaluate::evaluate_with_config;
+use interpreter::object::{
+    convert::{FromObject, ToObject},
+    object::Object,
+};
+use num_bigint::BigInt;
+mod shared;
+
+const MAX: &str = "9223372036854775807";
+
+fn promote() -> Config {
+    Config::new().with_overflow_policy(OverflowPolicy::Promote)
+}
+
+#[test]
+fn overflow_promotes() {
+    let tests = vec![
+        (format!("{} + 1", MAX), "9223372036854775808"),
+        (format!("-{} - 2", MAX), "-9223372036854775809"),
+        (
+            format!("{} * {}", MAX, MAX),
+            "85070591730234615847396907784232501249",
+        ),
+        (format!("-(-{} - 1)", MAX), "9223372036854775808"),
+        (format!("(-{} - 1) / -1", MAX), "9223372036854775808"),
+        (
+            "let k = 1024; k * k * k * k * k * k * k * k * k * k".to_string(),
+            "1267650600228229401496703205376",
+        ),
+    ];
+    for (input, expected) in tests.iter() {
+        let obj = match evaluate_with_config(input.as_str(), &promote()) {
+            Ok(object) => object,
+            Err(e) => panic!("{}: {}", input, e),
+        };
+        match obj {
+            Object::BigInteger(_) => assert_eq!(*expected, obj.string(), "{}", input),
+            _ => panic!("expected big integer for {}, got {}", input, obj.string()),
+        }
+        assert_eq!("INTEGER", obj.object_type().to_string());
+    }
+}
+
+#[test]
+fn results_that_fit_demote() {
+    let tests = vec![
+        (format!("{} + 1 - 1", MAX), i64::MAX),
+        (format!("({} + 1) / 2", MAX), 4611686018427387904),
+        (format!("{} * 4 / 8", MAX), 4611686018427387903),
+        (format!("-({} + 1)", MAX), i64::MIN),
+        (format!("({} + 10) - ({} + 3)", MAX, MAX), 7),
+        ("1 + 2".to_string(), 3),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate_with_config(input.as_str(), &promote()) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", input, e),
+        }
+    }
+}
+
+#[test]
+fn big_integer_comparison() {
+    let tests = vec![
+        (format!("{} + 1 > {}", MAX, MAX), true),
+        (format!("{} < {} + 1", MAX, MAX), true),
+        (format!("{} + 1 == {} + 1", MAX, MAX), true),
+        (format!("{} + 1 == {}", MAX, MAX), false),
+        (format!("{} + 1 != 1", MAX), true),
+        (format!("{} + 1 > 1.5", MAX), true),
+        (format!("{} + 1 == true", MAX), false),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate_with_config(input.as_str(), &promote()) {
+            Ok(object) => shared::test_bool_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", input, e),
+        }
+    }
+}
+
+#[test]
+fn big_integer_in_collections() {
+    let input = format!(
+        "let big = {} + 1; let h = {{big: \"big\"}}; [h[{} + 1], big]",
+        MAX, MAX
+    );
+    match evaluate_with_config(input.as_str(), &promote()) {
+        Ok(object) => assert_eq!("[big, 9223372036854775808]", object.string()),
+        Err(e) => panic!("{}: {}", input, e),
+    }
+}
+
+#[test]
+fn big_integer_errors() {
+    let tests = vec![
+        (format!("({} + 1) / 0", MAX), "1:2: division by zero"),
+        (
+            format!("({} + 1) + true", MAX),
+            "1:2: type mismatch: INTEGER + BOOLEAN",
+        ),
+        (
+            format!("!({} + 1) + 1", MAX),
+            "1:1: type mismatch: BOOLEAN + INTEGER",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate_with_config(input.as_str(), &promote()) {
+            Ok(object) => panic!("expected error for {}, got {}", input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", input),
+        }
+    }
+}
+
+#[test]
+fn big_integer_array_index() {
+    let tests = vec![
+        (format!("[1, 2, 3][{} + 1]", MAX), "null"),
+        (format!("[1, 2, 3][-{} - 2]", MAX), "null"),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate_with_config(input.as_str(), &promote()) {
+            Ok(object) => assert_eq!(*expected, object.string(), "{}", input),
+            Err(e) => panic!("{}: {}", input, e),
+        }
+    }
+
+    let config = promote().with_index_policy(IndexPolicy::Error);
+    let tests = vec![
+        (
+            format!("[1, 2, 3][{} + 1]", MAX),
+            "1:1: index out of bounds: index 9223372036854775808, length 3",
+        ),
+        (
+            format!("let a = [1]; a[{} + 1] = 2", MAX),
+            "1:14: index out of bounds: index 9223372036854775808, length 1",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate_with_config(input.as_str(), &config) {
+            Ok(object) => panic!("expected error for {}, got {}", input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", input),
+        }
+    }
+}
+
+#[test]
+fn big_integer_literals() {
+    let tests = vec![
+        ("99999999999999999999", "99999999999999999999"),
+        ("99999999999999999999 - 1", "99999999999999999998"),
+        ("-9223372036854775808", "-9223372036854775808"),
+        ("[18446744073709551616][0]", "18446744073709551616"),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate_with_config(*input, &promote()) {
+            Ok(object) => assert_eq!(*expected, object.string(), "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+
+    let tests = vec![
+        (
+            OverflowPolicy::Error,
+            "99999999999999999999",
+            "parser errors:\n\t1:1: integer literal out of range: 99999999999999999999",
+        ),
+        (
+            OverflowPolicy::Wrapping,
+            "1 + 9223372036854775808",
+            "parser errors:\n\t1:5: integer literal out of range: 9223372036854775808",
+        ),
+    ];
+    for (policy, input, expected_error) in tests.iter() {
+        let config = Config::new().with_overflow_policy(*policy);
+        match evaluate_with_config(*input, &config) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn big_integer_conversion() {
+    let big: BigInt = BigInt::from(i64::MAX) + 1;
+    let obj = big.clone().to_object();
+    assert_eq!(big, BigInt::from_object(&obj).unwrap());
+    assert_eq!(Object::new_integer(5), BigInt::from(5).to_object());
+    match i64::from_object(&obj) {
+        Ok(value) => panic!("expected error, got {}", value),
+        Err(e) => assert_eq!(
+            "cannot convert argument: INTEGER 9223372036854775808 is out of range for INTEGER",
+            e.to_string()
+        ),
+    }
+    assert_eq!(9223372036854775808.0, f64::from_object(&obj).unwrap());
+    let huge = (BigInt::from(1) << 1100usize).to_object();
+    match f64::from_object(&huge) {
+        Ok(value) => panic!("expected error, got {}", value),
+        Err(e) => assert!(
+            e.to_string().ends_with("is out of range for FLOAT"),
+            "{}",
+            e
+        ),
+    }
+}
@@ -0,0 +1,139 @@
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn builtin_functions() {
+    let tests = vec![
+        (r#"len("")"#, Object::new_integer(0)),
+        (r#"len("four")"#, Object::new_integer(4)),
+        (r#"len("hello world")"#, Object::new_integer(11)),
+        (r#"len("\u{1F600}")"#, Object::new_integer(1)),
+        ("len([1, 2, 3])", Object::new_integer(3)),
+        ("len([])", Object::new_integer(0)),
+        (r#"len({"a": 1, "b": 2})"#, Object::new_integer(2)),
+        ("first([1, 2, 3])", Object::new_integer(1)),
+        ("first([])", Object::null()),
+        ("last([1, 2, 3])", Object::new_integer(3)),
+        ("last([])", Object::null()),
+        (
+            "rest([1, 2, 3])",
+            Object::new_array(vec![Object::new_integer(2), Object::new_integer(3)]),
+        ),
+        ("rest([1])", Object::new_array(vec![])),
+        ("rest([])", Object::null()),
+        (
+            "push([], 1)",
+            Object::new_array(vec![Object::new_integer(1)]),
+        ),
+        (
+            "let a = [1]; let b = push(a, 2); a",
+            Object::new_array(vec![Object::new_integer(1)]),
+        ),
+        (r#"puts("hello", 1)"#, Object::null()),
+        ("len == len", Object::new_bool(true)),
+        ("len == first", Object::new_bool(false)),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => assert_eq!(*expected, object, "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn builtins_compose_with_functions() {
+    let input = "
+    let map = fn(arr, f) {
+        let iter = fn(arr, accumulated) {
+            if (len(arr) == 0) {
+                accumulated
+            } else {
+                iter(rest(arr), push(accumulated, f(first(arr))));
+            }
+        };
+        iter(arr, []);
+    };
+    let double = fn(x) { x * 2 };
+    let doubled = map([1, 2, 3, 4], double);
+    last(doubled) + len(doubled)";
+    match evaluate(input) {
+        Ok(object) => shared::test_integer_object(object, 12),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[test]
+fn builtins_can_be_shadowed() {
+    match evaluate("let len = fn(x) { 42 }; len([1])") {
+        Ok(object) => shared::test_integer_object(object, 42),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[test]
+fn builtin_errors() {
+    let tests = vec![
+        (
+            "len(1)",
+            "1:1: argument to `len` not supported, got INTEGER",
+            "len(1)",
+        ),
+        (
+            r#"len("one", "two")"#,
+            "1:1: wrong number of arguments: expected 1, got 2",
+            "len(\"one\", \"two\")",
+        ),
+        (
+            "first(1)",
+            "1:1: argument to `first` not supported, got INTEGER",
+            "first(1)",
+        ),
+        (
+            "last(true)",
+            "1:1: argument to `last` not supported, got BOOLEAN",
+            "last(true)",
+        ),
+        (
+            r#"rest("a")"#,
+            "1:1: argument to `rest` not supported, got STRING",
+            "rest(\"a\")",
+        ),
+        (
+            "push(1, 1)",
+            "1:1: argument to `push` not supported, got INTEGER",
+            "push(1, 1)",
+        ),
+        (
+            "push([])",
+            "1:1: wrong number of arguments: expected 2, got 1",
+            "push([])",
+        ),
+        (
+            "len(fn(x) { x })",
+            "1:1: argument to `len` not supported, got FUNCTION",
+            "len(fn (x) x)",
+        ),
+    ];
+    for (input, expected_error, expected_node) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input);
+                assert_eq!(*expected_node, e.node().unwrap().string(), "{}", *input);
+            }
+        }
+    }
+}
+
+#[test]
+fn builtin_object() {
+    match evaluate("len") {
+        Ok(object) => {
+            assert_eq!("BUILTIN", object.object_type().to_string());
+            assert_eq!("builtin function len", object.string());
+        }
+        Err(e) => panic!("{}", e),
+    }
+}
@@ -0,0 +1,29 @@
+use interpreter::evaluator::evaluate::evaluate;
+mod shared;
+
+#[test]
+fn comments_are_ignored() {
+    let input = "
+    // Doubles its argument.
+    let double = fn(x) {
+        x * 2 // no semicolon needed
+    };
+    /* Block comments may span lines
+       and /* nest */ inside each other. */
+    double(/* inline */ 21)";
+    match evaluate(input) {
+        Ok(object) => shared::test_integer_object(object, 42),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[test]
+fn unterminated_block_comment() {
+    match evaluate("let x = 1; /* never closed") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => assert_eq!(
+            "parser errors:\n\t1:12: unterminated block comment",
+            e.to_string()
+        ),
+    }
+}
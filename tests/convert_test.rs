@@ -0,0 +1,248 @@
+use std::collections::{BTreeMap, HashMap};
+
+use interpreter::evaluator::eval_error::EvalError;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::{
+    convert::{FromObject, ToObject},
+    object::Object,
+};
+mod shared;
+
+#[test]
+fn to_object() {
+    let tests = vec![
+        (5i64.to_object(), "5"),
+        (true.to_object(), "true"),
+        (().to_object(), "null"),
+        (String::from("monkey").to_object(), "monkey"),
+        ("monkey".to_object(), "monkey"),
+        (vec![1i64, 2, 3].to_object(), "[1, 2, 3]"),
+        (vec![vec![true], vec![]].to_object(), "[[true], []]"),
+        (Some(1i64).to_object(), "1"),
+        (None::<i64>.to_object(), "null"),
+        (
+            vec![(String::from("a"), 1i64)]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
+                .to_object(),
+            "{a: 1}",
+        ),
+    ];
+    for (object, expected) in tests.iter() {
+        assert_eq!(*expected, object.string());
+    }
+}
+
+#[test]
+fn maps_convert_in_key_order() {
+    let pairs = vec![
+        (String::from("b"), 2i64),
+        (String::from("c"), 3),
+        (String::from("a"), 1),
+    ];
+    let tests = vec![
+        pairs
+            .clone()
+            .into_iter()
+            .collect::<HashMap<_, _>>()
+            .to_object(),
+        pairs
+            .clone()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+            .to_object(),
+    ];
+    for object in tests.iter() {
+        assert_eq!("{a: 1, b: 2, c: 3}", object.string());
+    }
+
+    let mut interpreter = Interpreter::new();
+    let hash = interpreter.eval(r#"{"z": 1, "y": 2}"#).unwrap();
+    let map = BTreeMap::<String, i64>::from_object(&hash).unwrap();
+    assert_eq!(vec!["y", "z"], map.keys().collect::<Vec<_>>());
+}
+
+#[test]
+fn from_object() {
+    let mut interpreter = Interpreter::new();
+    let eval = |interpreter: &mut Interpreter, input: &str| match interpreter.eval(input) {
+        Ok(object) => object,
+        Err(e) => panic!("{}: {}", input, e),
+    };
+
+    assert_eq!(
+        5,
+        i64::from_object(&eval(&mut interpreter, "2 + 3")).unwrap()
+    );
+    assert!(bool::from_object(&eval(&mut interpreter, "1 < 2")).unwrap());
+    assert_eq!(
+        (),
+        <()>::from_object(&eval(&mut interpreter, "if (false) { 1 }")).unwrap()
+    );
+    assert_eq!(
+        "ab",
+        String::from_object(&eval(&mut interpreter, r#""a" + "b""#)).unwrap()
+    );
+    assert_eq!(
+        vec![1, 4, 9],
+        Vec::<i64>::from_object(&eval(&mut interpreter, "[1, 2 * 2, 3 * 3]")).unwrap()
+    );
+    assert_eq!(
+        None,
+        Option::<i64>::from_object(&eval(&mut interpreter, "[][0]")).unwrap()
+    );
+    assert_eq!(
+        Some(2),
+        Option::<i64>::from_object(&eval(&mut interpreter, "[2][0]")).unwrap()
+    );
+
+    let hash: HashMap<String, Vec<bool>> =
+        HashMap::from_object(&eval(&mut interpreter, r#"{"yes": [true], "no": []}"#)).unwrap();
+    assert_eq!(2, hash.len());
+    assert_eq!(vec![true], hash["yes"]);
+    assert!(hash["no"].is_empty());
+}
+
+#[test]
+fn from_object_errors() {
+    let tests = vec![
+        (
+            i64::from_object(&Object::new_bool(true)).map(|_| ()),
+            "cannot convert argument: expected INTEGER, got BOOLEAN",
+        ),
+        (
+            String::from_object(&Object::new_integer(1)).map(|_| ()),
+            "cannot convert argument: expected STRING, got INTEGER",
+        ),
+        (
+            Vec::<i64>::from_object(&Object::new_array(vec![Object::new_string("a")])).map(|_| ()),
+            "cannot convert argument: expected INTEGER, got STRING",
+        ),
+        (
+            HashMap::<i64, i64>::from_object(&Object::null()).map(|_| ()),
+            "cannot convert argument: expected HASH, got NULL",
+        ),
+    ];
+    for (result, expected_error) in tests.into_iter() {
+        match result {
+            Ok(_) => panic!("expected error: {}", expected_error),
+            Err(e) => assert_eq!(expected_error, e.to_string()),
+        }
+    }
+}
+
+#[test]
+fn typed_host_functions() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_typed_function("answer", || Ok(42i64));
+    interpreter.register_typed_function("add", |a: i64, b: i64| Ok(a + b));
+    interpreter.register_typed_function("sum", |values: Vec<i64>| Ok(values.iter().sum::<i64>()));
+    interpreter.register_typed_function("greet", |name: String, excited: Option<bool>| {
+        let suffix = if excited.unwrap_or(false) { "!" } else { "." };
+        Ok(format!("hello, {}{}", name, suffix))
+    });
+    interpreter.register_typed_function("checkedDiv", |a: i64, b: i64| {
+        if b == 0 {
+            return Err(EvalError::host("cannot divide by zero"));
+        }
+        Ok(a / b)
+    });
+    interpreter.register_typed_namespaced_function("str", "repeat", |s: String, n: i64| {
+        Ok(s.repeat(n as usize))
+    });
+
+    let tests = vec![
+        ("answer()", Object::new_integer(42)),
+        ("add(1, 2)", Object::new_integer(3)),
+        ("sum([1, 2, 3, 4])", Object::new_integer(10)),
+        (
+            r#"greet("monkey", true)"#,
+            Object::new_string("hello, monkey!"),
+        ),
+        (
+            r#"greet("monkey", [][0])"#,
+            Object::new_string("hello, monkey."),
+        ),
+        ("checkedDiv(9, 3)", Object::new_integer(3)),
+        (r#"str["repeat"]("ab", 2)"#, Object::new_string("abab")),
+    ];
+    for (input, expected) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => assert_eq!(*expected, object, "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn non_finite_floats_are_kept() {
+    assert_eq!("NaN", f64::NAN.to_object().string());
+    assert_eq!("inf", f64::INFINITY.to_object().string());
+    assert_eq!("-inf", f64::NEG_INFINITY.to_object().string());
+
+    let mut interpreter = Interpreter::new();
+    interpreter.register_typed_function("nan", || Ok(f64::NAN));
+    interpreter.register_typed_function("inf", || Ok(f64::INFINITY));
+    let tests = vec![
+        ("nan()", Ok("NaN")),
+        ("inf()", Ok("inf")),
+        ("nan() == nan()", Ok("false")),
+        ("inf() > 1", Ok("true")),
+        (
+            "nan() + 1",
+            Err("1:1: float result is not finite: (nan() + 1)"),
+        ),
+        (
+            "inf() * 0.5",
+            Err("1:1: float result is not finite: (inf() * 0.5)"),
+        ),
+    ];
+    for (input, expected) in tests.iter() {
+        match (interpreter.eval(*input), expected) {
+            (Ok(object), Ok(expected)) => assert_eq!(*expected, object.string(), "{}", *input),
+            (Err(e), Err(expected)) => assert_eq!(*expected, e.to_string(), "{}", *input),
+            (Ok(object), Err(_)) => {
+                panic!("expected error for {}, got {}", *input, object.string())
+            }
+            (Err(e), Ok(_)) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn typed_host_function_errors() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_typed_function("add", |a: i64, b: i64| Ok(a + b));
+    interpreter.register_typed_function("checkedDiv", |a: i64, b: i64| {
+        if b == 0 {
+            return Err(EvalError::host("cannot divide by zero"));
+        }
+        Ok(a / b)
+    });
+    let tests = vec![
+        (
+            r#"add(1, "2")"#,
+            "1:1: cannot convert argument: expected INTEGER, got STRING",
+            "add(1, \"2\")",
+        ),
+        (
+            "add(1)",
+            "1:1: wrong number of arguments: expected 2, got 1",
+            "add(1)",
+        ),
+        (
+            "checkedDiv(1, 0)",
+            "1:1: cannot divide by zero",
+            "checkedDiv(1, 0)",
+        ),
+    ];
+    for (input, expected_error, expected_node) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input);
+                assert_eq!(*expected_node, e.node().unwrap().string(), "{}", *input);
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use interpreter::evaluator::evaluate::evaluate;
+mod shared;
+
+#[test]
+fn error_handling() {
+    let tests = vec![
+        ("5 + true;", "1:1: type mismatch: INTEGER + BOOLEAN"),
+        ("5 + true; 5;", "1:1: type mismatch: INTEGER + BOOLEAN"),
+        ("true - 5", "1:1: type mismatch: BOOLEAN - INTEGER"),
+        ("-true", "1:1: unknown operator: -BOOLEAN"),
+        ("true + false;", "1:1: unknown operator: BOOLEAN + BOOLEAN"),
+        (
+            "5; true + false; 5",
+            "1:4: unknown operator: BOOLEAN + BOOLEAN",
+        ),
+        (
+            "if (10 > 1) { true + false; }",
+            "1:15: unknown operator: BOOLEAN + BOOLEAN",
+        ),
+        (
+            "if (10 > 1) { if (10 > 1) { return true + false; } return 1; }",
+            "1:36: unknown operator: BOOLEAN + BOOLEAN",
+        ),
+        (
+            "let f = fn(x) { x }; f + f",
+            "1:22: unknown operator: FUNCTION + FUNCTION",
+        ),
+        ("1 + (-false)", "1:6: unknown operator: -BOOLEAN"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn equality_across_types() {
+    let tests = vec![("1 == true", false), ("1 != true", true)];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}", e),
+        };
+        assert!(obj.is_some());
+        shared::test_bool_object(obj.unwrap(), *expected_value);
+    }
+}
@@ -0,0 +1,63 @@
+use interpreter::evaluator::{eval_error::EvalError, evaluate::evaluate};
+mod shared;
+
+#[test]
+fn errors_propagate_from_operands() {
+    let tests = vec![
+        ("-foo", "foo"),
+        ("!foo", "foo"),
+        ("(1 + foo) * 2", "foo"),
+        ("2 * (1 + foo)", "foo"),
+        ("if (foo) { 1 }", "foo"),
+        ("let f = fn(x) { x }; f(foo)", "foo"),
+        ("let x = foo; 1", "foo"),
+        ("return foo;", "foo"),
+    ];
+    for (input, expected_node) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(EvalError::IdentifierNotFound { name, node }) => {
+                assert_eq!("foo", name, "{}", *input);
+                assert_eq!(*expected_node, node.string(), "{}", *input);
+            }
+            Err(e) => panic!("unexpected error for {}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn errors_carry_call_expression() {
+    match evaluate("let f = fn(x) { x }; f(1, 2)") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => {
+            assert_eq!(
+                "1:22: wrong number of arguments: expected 1, got 2",
+                e.to_string()
+            );
+            assert_eq!("f(1, 2)", e.node().unwrap().string());
+        }
+    }
+}
+
+#[test]
+fn illegal_characters_are_parse_errors() {
+    match evaluate("let x = 1; x @ 2; x") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => assert_eq!(
+            "parser errors:\n\t1:14: illegal character '@'",
+            e.to_string()
+        ),
+    }
+}
+
+#[test]
+fn errors_report_source_position() {
+    match evaluate("let x = 1;\nlet y = x + true;") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => {
+            assert_eq!("2:9: type mismatch: INTEGER + BOOLEAN", e.to_string());
+            let span = e.span().unwrap();
+            assert_eq!((19, 27), (span.start, span.end));
+        }
+    }
+}
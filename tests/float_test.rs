@@ -0,0 +1,133 @@
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::object::object::Object;
+mod shared;
+
+fn test_float_object(obj: Object, expected: f64) {
+    match obj {
+        Object::Float(float) => assert_eq!(expected, float.value),
+        _ => panic!("expected float object, got {}", obj.string()),
+    }
+}
+
+#[test]
+fn float_literal() {
+    let tests = vec![
+        ("3.25", 3.25),
+        ("0.5", 0.5),
+        ("1e-9", 1e-9),
+        ("2E+3", 2000.0),
+        ("1.5e10", 1.5e10),
+        ("-2.5", -2.5),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => test_float_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn float_arithmetic() {
+    let tests = vec![
+        ("1 + 2.5", 3.5),
+        ("2.5 + 1", 3.5),
+        ("0.1 + 0.2", 0.1 + 0.2),
+        ("5.0 - 7", -2.0),
+        ("1.5 * 4", 6.0),
+        ("7.5 % 2", 1.5),
+        ("4 ** 0.5", 2.0),
+        ("2.0 ** -1", 0.5),
+        ("7 / 2.0", 3.5),
+        ("7.0 / 2", 3.5),
+        ("(1 + 2) * 0.5", 1.5),
+        ("let half = fn(x) { x / 2.0 }; half(5)", 2.5),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => test_float_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn float_comparison() {
+    let tests = vec![
+        ("1.5 < 2", true),
+        ("2 < 1.5", false),
+        ("1.5 > 1.25", true),
+        ("1 == 1.0", true),
+        ("1.0 == 1", true),
+        ("0.1 + 0.2 == 0.3", false),
+        ("2.5 != 2.5", false),
+        ("1 != 1.5", true),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_bool_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn float_printing_round_trips() {
+    let tests = vec![
+        ("3.14", "3.14"),
+        ("1.0 * 2", "2.0"),
+        ("0.1 + 0.2", "0.30000000000000004"),
+        ("1e-9", "1e-9"),
+        ("1e20", "1e20"),
+        ("[1.5, 2]", "[1.5, 2]"),
+    ];
+    for (input, expected) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => object,
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert_eq!(*expected, obj.string(), "{}", *input);
+        if let Object::Float(_) = obj {
+            assert_eq!(obj, evaluate(obj.string().as_str()).unwrap(), "{}", *input);
+        }
+    }
+}
+
+#[test]
+fn float_errors() {
+    let tests = vec![
+        ("1.5 / 0", "1:1: division by zero"),
+        ("1.5 + true", "1:1: type mismatch: FLOAT + BOOLEAN"),
+        (r#""a" + 1.5"#, "1:1: type mismatch: STRING + FLOAT"),
+        ("{1.5: 1}", "1:2: unusable as hash key: FLOAT"),
+        ("1e308 * 10", "1:1: float result is not finite: (1e308 * 10)"),
+        (
+            "let big = 1e308 * 2; big - big",
+            "1:11: float result is not finite: (1e308 * 2)",
+        ),
+        ("10.0 ** 400", "1:1: float result is not finite: (10.0 ** 400)"),
+        ("(-8.0) ** 0.5", "1:2: float result is not finite: ((-8.0) ** 0.5)"),
+        (
+            "1e400",
+            "parser errors:\n\t1:1: float literal out of range: 1e400",
+        ),
+        (
+            "1e400 - 1e400",
+            "parser errors:\n\t1:1: float literal out of range: 1e400\n\t1:9: float literal out of range: 1e400",
+        ),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn float_objects_are_reflexive() {
+    let nan = Object::new_float(f64::NAN);
+    assert_eq!(nan, nan.clone());
+    assert_ne!(nan, Object::new_float(1.0));
+    assert_eq!(Object::new_float(0.0), Object::new_float(-0.0));
+}
@@ -0,0 +1,146 @@
+use std::thread;
+
+use interpreter::ast::token_node::TokenNode;
+use interpreter::evaluator::config::Config;
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn function_object() {
+    let obj = match evaluate("fn(x) { x + 2; };") {
+        Ok(object) => object,
+        Err(e) => panic!("{}", e),
+    };
+    let function = match obj {
+        Object::Function(function) => function,
+        _ => panic!("expected function object, got {}", obj.string()),
+    };
+    assert_eq!(1, function.parameters.len());
+    assert_eq!("x", function.parameters[0].value);
+    assert_eq!("(x + 2)", function.body.statements[0].string());
+}
+
+#[test]
+fn function_application() {
+    let tests = vec![
+        ("let identity = fn(x) { x; }; identity(5);", 5),
+        ("let identity = fn(x) { return x; }; identity(5);", 5),
+        ("let double = fn(x) { x * 2; }; double(5);", 10),
+        ("let add = fn(x, y) { x + y; }; add(5, 5);", 10),
+        ("let add = fn(x, y) { x + y; }; add(5 + 5, add(5, 5));", 20),
+        ("fn(x) { x; }(5)", 5),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn closures() {
+    let tests = vec![
+        (
+            "let adder = fn(x) { fn(y) { x + y } }; let add_two = adder(2); add_two(3);",
+            5,
+        ),
+        (
+            "let apply = fn(f, x) { f(x) }; let square = fn(x) { x * x }; apply(square, 4);",
+            16,
+        ),
+        ("let x = 10; let shadow = fn(x) { x }; shadow(1) + x;", 11),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn function_errors() {
+    let tests = vec![
+        (
+            "let add = fn(x, y) { x + y; }; add(1);",
+            "1:32: wrong number of arguments: expected 2, got 1",
+        ),
+        ("let x = 5; x(1);", "1:12: not a function: INTEGER"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+// Test threads get a smaller stack than the main thread, so recursion close to
+// the default limit runs on a thread with the main thread's usual 8 MiB.
+#[test]
+fn deep_recursion_is_an_error() {
+    let handle = thread::Builder::new()
+        .stack_size(8 * 1024 * 1024)
+        .spawn(|| {
+            let tests = vec![
+                (
+                    "let f = fn(n) { if (n == 0) { 0 } else { f(n - 1) } }; f(100000)",
+                    "1:42: maximum call depth of 128 exceeded",
+                ),
+                (
+                    "let a = []; let i = 0; while (i < 200) { a = push(a, i); i += 1 }; \
+                     let map = fn(a, g) { if (len(a) == 0) { [] } else { \
+                     push(map(rest(a), g), g(first(a))) } }; map(a, fn(x) { x })",
+                    "1:125: maximum call depth of 128 exceeded",
+                ),
+            ];
+            for (input, expected_error) in tests.iter() {
+                match evaluate(*input) {
+                    Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+                    Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+                }
+            }
+            let input = "let f = fn(n) { if (n == 0) { 0 } else { 1 + f(n - 1) } }; f(127)";
+            match evaluate(input) {
+                Ok(object) => shared::test_integer_object(object, 127),
+                Err(e) => panic!("{}: {}", input, e),
+            }
+        })
+        .unwrap();
+    handle.join().unwrap();
+}
+
+#[test]
+fn max_call_depth() {
+    let config = Config::new().with_max_call_depth(10);
+    let mut interpreter = Interpreter::with_config(config);
+    let tests = vec![
+        (
+            "let f = fn(n) { if (n == 0) { 0 } else { 1 + f(n - 1) } }; f(9)",
+            Ok(9),
+        ),
+        ("f(10)", Err("1:46: maximum call depth of 10 exceeded")),
+        ("f(9)", Ok(9)),
+    ];
+    for (input, expected) in tests.iter() {
+        match (interpreter.eval(*input), expected) {
+            (Ok(object), Ok(expected_value)) => {
+                shared::test_integer_object(object, *expected_value)
+            }
+            (Err(e), Err(expected_error)) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input)
+            }
+            (Ok(object), Err(_)) => {
+                panic!("expected error for {}, got {}", *input, object.string())
+            }
+            (Err(e), Ok(_)) => panic!("{}: {}", *input, e),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::object::{hash_key::HashKey, object::Object};
+mod shared;
+
+#[test]
+fn hash_literal() {
+    let input = r#"let two = "two";
+    {
+        "one": 10 - 9,
+        two: 1 + 1,
+        "thr" + "ee": 6 / 2,
+        4: 4,
+        true: 5,
+        false: 6
+    }"#;
+    let obj = match evaluate(input) {
+        Ok(object) => object,
+        Err(e) => panic!("{}", e),
+    };
+    let hash = match obj {
+        Object::Hash(hash) => hash,
+        _ => panic!("expected hash object, got {}", obj.string()),
+    };
+    let expected = vec![
+        (HashKey::String(String::from("one")), 1),
+        (HashKey::String(String::from("two")), 2),
+        (HashKey::String(String::from("three")), 3),
+        (HashKey::Integer(4), 4),
+        (HashKey::Bool(true), 5),
+        (HashKey::Bool(false), 6),
+    ];
+    assert_eq!(expected.len(), hash.len());
+    for (key, expected_value) in expected.iter() {
+        match hash.get(key) {
+            Some(value) => shared::test_integer_object(value.clone(), *expected_value),
+            None => panic!("no pair for {:?}", key),
+        }
+    }
+}
+
+#[test]
+fn hash_index_expression() {
+    let tests = vec![
+        (r#"{"foo": 5}["foo"]"#, Object::new_integer(5)),
+        (r#"{"foo": 5}["bar"]"#, Object::null()),
+        (
+            r#"let key = "foo"; {"foo": 5}[key]"#,
+            Object::new_integer(5),
+        ),
+        (r#"{}["foo"]"#, Object::null()),
+        ("{5: 5}[5]", Object::new_integer(5)),
+        ("{true: 5}[true]", Object::new_integer(5)),
+        ("{false: 5}[false]", Object::new_integer(5)),
+        ("{1: 5}[true]", Object::null()),
+        (
+            r#"{"a": {"b": [1, 2]}}["a"]["b"][1]"#,
+            Object::new_integer(2),
+        ),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => assert_eq!(*expected, object, "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn hash_preserves_insertion_order() {
+    let tests = vec![
+        (r#"{"name": "monkey", 1: true}"#, "{name: monkey, 1: true}"),
+        ("{3: 1, 1: 2, 2: 3}", "{3: 1, 1: 2, 2: 3}"),
+        ("{1: 1, 2: 2, 1: 3}", "{1: 3, 2: 2}"),
+        ("{}", "{}"),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => assert_eq!(*expected, object.string(), "{}", *input),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn hash_equality() {
+    let tests = vec![
+        ("{1: 2, 3: 4} == {3: 4, 1: 2}", true),
+        ("{1: 2} == {1: 3}", false),
+        ("{1: 2} == {1: 2, 3: 4}", false),
+        (r#"{"a": [1]} != {"a": [1]}"#, false),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        shared::test_bool_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn unusable_as_hash_key() {
+    let tests = vec![
+        (
+            r#"{"name": "Monkey"}[fn(x) { x }];"#,
+            "1:20: unusable as hash key: FUNCTION",
+            "fn (x) x",
+        ),
+        ("{[1]: 2}", "1:2: unusable as hash key: ARRAY", "[1]"),
+        ("{{}: 2}", "1:2: unusable as hash key: HASH", "{}"),
+    ];
+    for (input, expected_error, expected_node) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input);
+                assert_eq!(*expected_node, e.node().unwrap().string(), "{}", *input);
+            }
+        }
+    }
+}
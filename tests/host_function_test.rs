@@ -0,0 +1,101 @@
+use std::{cell::RefCell, rc::Rc};
+
+use interpreter::evaluator::eval_error::EvalError;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::object::Object;
+mod shared;
+
+fn add(arguments: &[Object]) -> Result<Object, EvalError> {
+    let mut sum = 0;
+    for argument in arguments.iter() {
+        match argument {
+            Object::Integer(integer) => sum += integer.value,
+            _ => return Err(EvalError::host("add expects integers")),
+        }
+    }
+    Ok(Object::new_integer(sum))
+}
+
+#[test]
+fn register_function() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_function("add", add);
+    let tests = vec![
+        ("add(1, 2, 3)", 6),
+        ("let f = fn(x) { add(x, x) }; f(4)", 8),
+        ("let g = add; g(10)", 10),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn register_closure_with_captured_state() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    let mut interpreter = Interpreter::new();
+    let log = Rc::clone(&calls);
+    interpreter.register_function("log", move |arguments| {
+        for argument in arguments.iter() {
+            log.borrow_mut().push(argument.string());
+        }
+        Ok(Object::null())
+    });
+    interpreter
+        .eval(r#"log("start"); let x = 2 * 3; log(x, [x]);"#)
+        .unwrap();
+    assert_eq!(vec!["start", "6", "[6]"], *calls.borrow());
+}
+
+#[test]
+fn register_namespaced_function() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_namespaced_function("math", "add", add);
+    interpreter.register_namespaced_function("math", "negate", |arguments| match arguments {
+        [Object::Integer(integer)] => Ok(Object::new_integer(-integer.value)),
+        _ => Err(EvalError::host("negate expects one integer")),
+    });
+    let tests = vec![
+        (r#"math["add"](1, 2)"#, 3),
+        (r#"math["negate"](5)"#, -5),
+        (r#"let m = math; m["negate"](m["add"](1, 1))"#, -2),
+        ("len(math)", 2),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+    assert_eq!(
+        "builtin function math.add",
+        interpreter.eval(r#"math["add"]"#).unwrap().string()
+    );
+}
+
+#[test]
+fn host_function_errors() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_function("add", add);
+    interpreter.register_namespaced_function("math", "add", add);
+    let tests = vec![
+        ("add(1, true)", "1:1: add expects integers", "add(1, true)"),
+        (
+            r#"math["add"]("a")"#,
+            "1:1: add expects integers",
+            "(math[\"add\"])(\"a\")",
+        ),
+    ];
+    for (input, expected_error, expected_node) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => {
+                assert_eq!(*expected_error, e.to_string(), "{}", *input);
+                assert_eq!(*expected_node, e.node().unwrap().string(), "{}", *input);
+            }
+        }
+    }
+}
@@ -28,3 +28,24 @@ fn if_else_expression() {
         assert_eq!(*expected_value, obj.unwrap(), "{}", *input);
     }
 }
+
+#[test]
+fn else_if_chains() {
+    let grade = "let grade = fn(n) { if (n >= 90) { \"A\" } else if (n >= 80) { \"B\" } else if (n >= 70) { \"C\" } else { \"F\" } };";
+    let tests = vec![
+        (format!("{} grade(95)", grade), "A"),
+        (format!("{} grade(85)", grade), "B"),
+        (format!("{} grade(70)", grade), "C"),
+        (format!("{} grade(10)", grade), "F"),
+        (
+            String::from("if (false) { 1 } else if (false) { 2 }"),
+            "null",
+        ),
+    ];
+    for (input, expected) in tests.iter() {
+        match evaluate(input.as_str()) {
+            Ok(object) => assert_eq!(*expected, object.string(), "{}", input),
+            Err(e) => panic!("{}: {}", input, e),
+        }
+    }
+}
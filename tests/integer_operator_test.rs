@@ -0,0 +1,162 @@
+use interpreter::evaluator::config::{Config, OverflowPolicy};
+use interpreter::evaluator::evaluate::{evaluate, evaluate_with_config};
+use interpreter::object::object::Object;
+mod shared;
+
+const MAX: &str = "9223372036854775807";
+
+#[test]
+fn modulo_and_power() {
+    let tests = vec![
+        ("7 % 3", 1),
+        ("-7 % 3", -1),
+        ("7 % -3", 1),
+        ("2 + 7 % 3 * 2", 4),
+        ("2 ** 10", 1024),
+        ("2 ** 3 ** 2", 512),
+        ("-2 ** 2", -4),
+        ("(-2) ** 3", -8),
+        ("5 ** 0", 1),
+        ("2 * 3 ** 2", 18),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn bitwise_operators() {
+    let tests = vec![
+        ("6 & 3", 2),
+        ("6 | 3", 7),
+        ("6 ^ 3", 5),
+        ("~5", -6),
+        ("~-1", 0),
+        ("1 << 4", 16),
+        ("-16 >> 2", -4),
+        ("1 >> 70", 0),
+        ("-1 >> 70", -1),
+        ("0 << 100", 0),
+        ("1 + 2 << 1", 6),
+        ("1 | 2 ^ 3 & 4", 3),
+        ("-1 << 63", i64::MIN),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn integer_operator_errors() {
+    let tests = vec![
+        ("2 ** -1", "1:1: negative exponent"),
+        ("1 << -1", "1:1: negative shift amount"),
+        ("let n = -2; 8 >> n", "1:13: negative shift amount"),
+        ("5 % 0", "1:1: division by zero"),
+        ("2 ** 63", "1:1: integer overflow: (2 ** 63)"),
+        ("1 << 63", "1:1: integer overflow: (1 << 63)"),
+        ("1.5 & 1", "1:1: unknown operator: FLOAT & INTEGER"),
+        ("~true", "1:1: unknown operator: ~BOOLEAN"),
+        ("true ^ 1", "1:1: type mismatch: BOOLEAN ^ INTEGER"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
+
+#[test]
+fn overflow_policies() {
+    let tests = vec![
+        (OverflowPolicy::Wrapping, "2 ** 64", "0"),
+        (OverflowPolicy::Wrapping, "3 ** 41", "-420491770248316829"),
+        (OverflowPolicy::Wrapping, "1 << 64", "0"),
+        (OverflowPolicy::Saturating, "2 ** 64", MAX),
+        (
+            OverflowPolicy::Saturating,
+            "(-2) ** 65",
+            "-9223372036854775808",
+        ),
+        (
+            OverflowPolicy::Saturating,
+            "-1 << 64",
+            "-9223372036854775808",
+        ),
+        (OverflowPolicy::Promote, "2 ** 64", "18446744073709551616"),
+        (OverflowPolicy::Promote, "1 << 64", "18446744073709551616"),
+        (OverflowPolicy::Promote, "(2 ** 64) % 7", "2"),
+        (OverflowPolicy::Promote, "(1 << 64) >> 63", "2"),
+        (
+            OverflowPolicy::Promote,
+            "~(2 ** 64)",
+            "-18446744073709551617",
+        ),
+        (
+            OverflowPolicy::Promote,
+            "(2 ** 64) & (2 ** 64 + 1)",
+            "18446744073709551616",
+        ),
+    ];
+    for (policy, input, expected) in tests.into_iter() {
+        let config = Config::new().with_overflow_policy(policy);
+        match evaluate_with_config(input, &config) {
+            Ok(object) => assert_eq!(expected, object.string(), "{}", input),
+            Err(e) => panic!("{}: {}", input, e),
+        }
+    }
+}
+
+#[test]
+fn promoted_operands_keep_their_errors() {
+    let config = Config::new().with_overflow_policy(OverflowPolicy::Promote);
+    let tests = vec![
+        ("(2 ** 64) ** -1", "1:2: negative exponent"),
+        ("(2 ** 64) << -1", "1:2: negative shift amount"),
+        ("(2 ** 64) % 0", "1:2: division by zero"),
+        ("2 ** (2 ** 40)", "1:1: integer overflow: (2 ** (2 ** 40))"),
+        (
+            "3 ** 4000000000",
+            "1:1: integer overflow: (3 ** 4000000000)",
+        ),
+        (
+            "(2 ** 64) ** 1000000",
+            "1:2: integer overflow: ((2 ** 64) ** 1000000)",
+        ),
+        (
+            "1 << 9223372036854775807",
+            "1:1: integer overflow: (1 << 9223372036854775807)",
+        ),
+        ("1 << 10000000", "1:1: integer overflow: (1 << 10000000)"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate_with_config(*input, &config) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+    let tests = vec![
+        ("(2 ** 100000) >> 99999", 2),
+        ("(1 << 100000) >> 99998", 4),
+        ("(-1) ** 4000000001", -1),
+        ("0 << 9223372036854775807", 0),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate_with_config(*input, &config) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+    match evaluate_with_config("(1 << 64) ** 0", &config) {
+        Ok(Object::Integer(integer)) => assert_eq!(1, integer.value),
+        Ok(object) => panic!("expected integer, got {}", object.string()),
+        Err(e) => panic!("{}", e),
+    }
+}
@@ -0,0 +1,91 @@
+use interpreter::evaluator::config::{Config, OverflowPolicy};
+use interpreter::evaluator::evaluate::{evaluate, evaluate_with_config};
+mod shared;
+
+const MAX: &str = "9223372036854775807";
+
+#[test]
+fn overflow_error_policy() {
+    let tests = vec![
+        format!("{} + 1", MAX),
+        format!("-{} - 2", MAX),
+        format!("{} * 2", MAX),
+        format!("-(-{} - 1)", MAX),
+        format!("(-{} - 1) / -1", MAX),
+    ];
+    for input in tests.iter() {
+        match evaluate(input.as_str()) {
+            Ok(object) => panic!("expected error for {}, got {}", input, object.string()),
+            Err(e) => assert!(
+                e.to_string()
+                    .starts_with(format!("{}: integer overflow: ", e.span().unwrap()).as_str()),
+                "{}: {}",
+                input,
+                e
+            ),
+        }
+    }
+}
+
+#[test]
+fn overflow_wrapping_policy() {
+    let config = Config::new().with_overflow_policy(OverflowPolicy::Wrapping);
+    let tests = vec![
+        (format!("{} + 1", MAX), i64::MIN),
+        (format!("-{} - 2", MAX), i64::MAX),
+        (format!("{} * 2", MAX), -2),
+        (format!("-(-{} - 1)", MAX), i64::MIN),
+        (format!("(-{} - 1) / -1", MAX), i64::MIN),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate_with_config(input.as_str(), &config) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", input, e),
+        };
+        assert!(obj.is_some(), "{}", input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn overflow_saturating_policy() {
+    let config = Config::new().with_overflow_policy(OverflowPolicy::Saturating);
+    let tests = vec![
+        (format!("{} + 1", MAX), i64::MAX),
+        (format!("-{} - 2", MAX), i64::MIN),
+        (format!("{} * -2", MAX), i64::MIN),
+        (format!("-(-{} - 1)", MAX), i64::MAX),
+        (format!("(-{} - 1) / -1", MAX), i64::MAX),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate_with_config(input.as_str(), &config) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", input, e),
+        };
+        assert!(obj.is_some(), "{}", input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn division_by_zero() {
+    let policies = vec![
+        OverflowPolicy::Error,
+        OverflowPolicy::Wrapping,
+        OverflowPolicy::Saturating,
+    ];
+    for policy in policies.into_iter() {
+        let config = Config::new().with_overflow_policy(policy);
+        let tests = vec![
+            ("1 / 0", "1:1: division by zero"),
+            ("let x = 0; 10 / x", "1:12: division by zero"),
+            ("fn(x) { 1 / x }(0)", "1:9: division by zero"),
+        ];
+        for (input, expected_error) in tests.iter() {
+            match evaluate_with_config(*input, &config) {
+                Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+                Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+            }
+        }
+    }
+}
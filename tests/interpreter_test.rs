@@ -0,0 +1,77 @@
+use interpreter::evaluator::config::{Config, OverflowPolicy};
+use interpreter::evaluator::eval_error::EvalError;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn state_persists_between_evaluations() {
+    let mut interpreter = Interpreter::new();
+    let snippets = vec![
+        ("let x = 5;", Object::null()),
+        ("let double = fn(n) { n * 2 };", Object::null()),
+        ("double(x)", Object::new_integer(10)),
+        ("let y = double(x) + 1;", Object::null()),
+        ("y", Object::new_integer(11)),
+    ];
+    for (input, expected_value) in snippets.iter() {
+        let obj = match interpreter.eval(*input) {
+            Ok(object) => object,
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert_eq!(*expected_value, obj, "{}", *input);
+    }
+    assert_eq!(Some(Object::new_integer(11)), interpreter.get("y"));
+}
+
+#[test]
+fn sessions_are_isolated() {
+    let mut first = Interpreter::new();
+    let mut second = Interpreter::new();
+    first.eval("let x = 1;").unwrap();
+    match second.eval("x") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => assert_eq!("1:1: identifier not found: x", e.to_string()),
+    }
+}
+
+#[test]
+fn host_bindings() {
+    let mut interpreter = Interpreter::new();
+    interpreter.set("answer", Object::new_integer(42));
+    let obj = interpreter.eval("answer / 2").unwrap();
+    shared::test_integer_object(obj, 21);
+}
+
+#[test]
+fn config_per_instance() {
+    let mut wrapping =
+        Interpreter::with_config(Config::new().with_overflow_policy(OverflowPolicy::Wrapping));
+    let mut checked = Interpreter::new();
+    let input = "9223372036854775807 + 1";
+
+    shared::test_integer_object(wrapping.eval(input).unwrap(), i64::MIN);
+    assert!(checked.eval(input).is_err());
+
+    checked.config_mut().overflow_policy = OverflowPolicy::Saturating;
+    shared::test_integer_object(checked.eval(input).unwrap(), i64::MAX);
+}
+
+#[test]
+fn parse_errors() {
+    let mut interpreter = Interpreter::new();
+    match interpreter.eval("let = 5;") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(EvalError::ParseErrors { errors }) => assert!(!errors.is_empty()),
+        Err(e) => panic!("unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn errors_name_the_source_file() {
+    let mut interpreter = Interpreter::new();
+    match interpreter.eval_file("let x = 1;\n  x + y", "main.mk") {
+        Ok(object) => panic!("expected error, got {}", object.string()),
+        Err(e) => assert_eq!("main.mk:2:7: identifier not found: y", e.to_string()),
+    }
+}
@@ -0,0 +1,37 @@
+use interpreter::evaluator::evaluate::evaluate;
+mod shared;
+
+#[test]
+fn let_statement() {
+    let tests = vec![
+        ("let a = 5; a;", 5),
+        ("let a = 5 * 5; a;", 25),
+        ("let a = 5; let b = a; b;", 5),
+        ("let a = 5; let b = a; let c = a + b + 5; c;", 15),
+        ("let x = 5; x * 2", 10),
+        ("let my_var = 5; let x2 = my_var * 2; x2", 10),
+        ("let _ = 1; let __a_1 = 2; _ + __a_1", 3),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}", e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        shared::test_integer_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn identifier_not_found() {
+    let tests = vec![
+        ("foobar", "1:1: identifier not found: foobar"),
+        ("let a = 5; a + b", "1:16: identifier not found: b"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
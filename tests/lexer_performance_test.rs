@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+use interpreter::lexer::Lexer;
+
+fn generate_script(statements: usize) -> String {
+    let mut script = String::new();
+    for i in 0..statements {
+        script.push_str(&format!(
+            "let value_{} = fn(x) {{ if (x <= {}) {{ \"ünïcode\" }} else {{ [x, {}.5] }} }};\n",
+            i, i, i
+        ));
+    }
+    script
+}
+
+fn time_to_lex(input: &str) -> (usize, Duration) {
+    let start = Instant::now();
+    let tokens = Lexer::new(input).read_tokens().len();
+    (tokens, start.elapsed())
+}
+
+// A quadratic lexer takes ~64 times longer on an input 8 times larger; a
+// linear one ~8 times. The bound leaves plenty of room for timing noise.
+#[test]
+fn lexing_is_linear_in_input_size() {
+    let small = generate_script(400);
+    let large = generate_script(3200);
+    assert!(large.len() > 200_000, "{}", large.len());
+
+    // Warm up allocations before timing.
+    time_to_lex(&small);
+    let (small_tokens, small_time) = time_to_lex(&small);
+    let (large_tokens, large_time) = time_to_lex(&large);
+
+    assert_eq!(small_tokens * 8 - 7, large_tokens);
+    let ratio = large_time.as_secs_f64() / small_time.as_secs_f64();
+    assert!(
+        ratio < 24.0,
+        "lexing 8x the input took {:.1}x as long ({:?} vs {:?})",
+        ratio,
+        large_time,
+        small_time
+    );
+    assert!(
+        large_time < Duration::from_secs(2),
+        "lexing {} bytes took {:?}",
+        large.len(),
+        large_time
+    );
+}
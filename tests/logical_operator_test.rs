@@ -0,0 +1,96 @@
+use std::{cell::Cell, rc::Rc};
+
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::interpreter::Interpreter;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn comparison_operators() {
+    let tests = vec![
+        ("1 <= 2", true),
+        ("2 <= 2", true),
+        ("3 <= 2", false),
+        ("1 >= 2", false),
+        ("2 >= 2", true),
+        ("3 >= 2", true),
+        ("1.5 <= 1.5", true),
+        ("2 >= 2.5", false),
+        (r#""a" <= "b""#, true),
+        (r#""b" >= "b""#, true),
+        ("1 + 1 <= 2 == true", true),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_bool_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn logical_operators() {
+    let tests = vec![
+        ("true && true", true),
+        ("true && false", false),
+        ("false && true", false),
+        ("true || false", true),
+        ("false || false", false),
+        ("false || true", true),
+        ("1 < 2 && 2 < 3", true),
+        ("1 > 2 || 2 > 3", false),
+        ("false && true || true", true),
+        ("true || true && false", true),
+        ("5 && 0", true),
+        ("if (false) { 1 } || [1]", true),
+        ("!(true && false)", true),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_bool_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn logical_operators_short_circuit() {
+    let calls = Rc::new(Cell::new(0));
+    let mut interpreter = Interpreter::new();
+    let counter = Rc::clone(&calls);
+    interpreter.register_function("touch", move |_| {
+        counter.set(counter.get() + 1);
+        Ok(Object::new_bool(true))
+    });
+    let tests = vec![
+        ("false && touch()", false, 0),
+        ("true || touch()", true, 0),
+        ("false && undefined", false, 0),
+        ("true || 1 / 0", true, 0),
+        ("true && touch()", true, 1),
+        ("false || touch()", true, 2),
+    ];
+    for (input, expected_value, expected_calls) in tests.iter() {
+        match interpreter.eval(*input) {
+            Ok(object) => shared::test_bool_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+        assert_eq!(*expected_calls, calls.get(), "{}", *input);
+    }
+}
+
+#[test]
+fn logical_operator_errors() {
+    let tests = vec![
+        ("true && undefined", "1:9: identifier not found: undefined"),
+        ("undefined || true", "1:1: identifier not found: undefined"),
+        ("1 <= true", "1:1: type mismatch: INTEGER <= BOOLEAN"),
+        ("true >= false", "1:1: unknown operator: BOOLEAN >= BOOLEAN"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use interpreter::evaluator::evaluate::evaluate;
+mod shared;
+
+#[test]
+fn while_loops() {
+    let tests = vec![
+        ("let i = 0; while (i < 10) { i += 1 }; i", 10),
+        ("let i = 0; let sum = 0; while (i < 5) { i += 1; sum += i }; sum", 15),
+        ("let i = 10; while (i < 5) { i = 0 }; i", 10),
+        ("let i = 0; while (true) { i += 1; if (i == 7) { break } }; i", 7),
+        (
+            "let i = 0; let odd = 0; while (i < 10) { i += 1; if (i % 2 == 0) { continue } odd += 1 }; odd",
+            5,
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn for_loops() {
+    let tests = vec![
+        ("let sum = 0; for (x in [1, 2, 3, 4]) { sum += x }; sum", 10),
+        ("let n = 0; for (x in []) { n += 1 }; n", 0),
+        (
+            "let sum = 0; for (x in [1, 2, 3, 4, 5]) { if (x == 2) { continue } if (x == 4) { break } sum += x }; sum",
+            4,
+        ),
+        (
+            "let h = {\"a\": 1, \"b\": 2}; let sum = 0; for (k in h) { sum += h[k] }; sum",
+            3,
+        ),
+        ("let n = 0; for (c in \"hello\") { n += 1 }; n", 5),
+        (
+            "let count = 0; for (x in [1, 2]) { for (y in [1, 2, 3]) { if (y == 2) { break } count += 1 } }; count",
+            2,
+        ),
+        ("let x = 5; for (x in [1, 2]) { }; x", 5),
+        (
+            "let fs = []; for (x in [1, 2]) { fs = push(fs, fn() { x }) }; fs[0]() + fs[1]() * 10",
+            21,
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn return_exits_loops() {
+    let tests = vec![
+        (
+            "let find = fn(xs, v) { for (x in xs) { if (x == v) { return x * 10 } }; -1 }; find([1, 2, 3], 2)",
+            20,
+        ),
+        (
+            "let f = fn() { let i = 0; while (true) { i += 1; if (i > 3) { return i } } }; f()",
+            4,
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => shared::test_integer_object(object, *expected_value),
+            Err(e) => panic!("{}: {}", *input, e),
+        }
+    }
+}
+
+#[test]
+fn long_loops_do_not_recurse() {
+    let input = "let i = 0; let sum = 0; while (i < 100000) { i += 1; sum += i }; sum";
+    match evaluate(input) {
+        Ok(object) => shared::test_integer_object(object, 5000050000),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[test]
+fn loop_errors() {
+    let tests = vec![
+        ("break", "1:1: break outside of a loop"),
+        ("if (true) { continue }", "1:13: continue outside of a loop"),
+        (
+            "let f = fn() { break }; while (true) { f() }",
+            "1:16: break outside of a loop",
+        ),
+        (
+            "while (true) { let x = break; }",
+            "1:24: break outside of a loop",
+        ),
+        (
+            "let x = 0; while (true) { x = continue; }",
+            "1:31: continue outside of a loop",
+        ),
+        (
+            "let f = fn() { return break; }; while (true) { f() }",
+            "1:23: break outside of a loop",
+        ),
+        (
+            "while (true) { 1 + break }",
+            "1:20: break outside of a loop",
+        ),
+        (
+            "while (true) { -continue }",
+            "1:17: continue outside of a loop",
+        ),
+        ("while (true) { [break] }", "1:17: break outside of a loop"),
+        (
+            "while (true) { {1: break} }",
+            "1:20: break outside of a loop",
+        ),
+        (
+            "while (true) { puts(continue) }",
+            "1:21: continue outside of a loop",
+        ),
+        ("for (x in 5) { }", "1:11: not iterable: INTEGER"),
+        ("while (true) { y }", "1:16: identifier not found: y"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}
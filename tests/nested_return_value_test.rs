@@ -0,0 +1,71 @@
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn nested_return_value() {
+    let tests = vec![
+        (
+            "if (true) { if (true) { return 10; } return 1; }",
+            Object::new_integer(10),
+        ),
+        (
+            "if (10 > 1) { if (10 > 1) { return 10; } return 1; } 9;",
+            Object::new_integer(10),
+        ),
+        (
+            "if (true) { if (false) { return 10; } return 1; }",
+            Object::new_integer(1),
+        ),
+        (
+            "if (true) { if (true) { if (true) { return 3; } 2; } 1; }",
+            Object::new_integer(3),
+        ),
+        (
+            "if (true) { if (false) { 10 } else { return 20; } 1; }",
+            Object::new_integer(20),
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}", e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        assert_eq!(*expected_value, obj.unwrap(), "{}", *input);
+    }
+}
+
+#[test]
+fn return_from_function_body() {
+    let tests = vec![
+        (
+            "let f = fn(x) { if (x > 5) { if (x > 10) { return 2; } return 1; } 0; }; f(20);",
+            Object::new_integer(2),
+        ),
+        (
+            "let f = fn(x) { if (x > 5) { if (x > 10) { return 2; } return 1; } 0; }; f(7);",
+            Object::new_integer(1),
+        ),
+        (
+            "let f = fn(x) { if (x > 5) { if (x > 10) { return 2; } return 1; } 0; }; f(3);",
+            Object::new_integer(0),
+        ),
+        (
+            "let f = fn() { return 1; }; let g = fn() { f(); return 2; }; g();",
+            Object::new_integer(2),
+        ),
+        (
+            "let f = fn() { return 1; }; f() + f(); 5;",
+            Object::new_integer(5),
+        ),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}", e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        assert_eq!(*expected_value, obj.unwrap(), "{}", *input);
+    }
+}
@@ -0,0 +1,80 @@
+use interpreter::evaluator::evaluate::evaluate;
+use interpreter::object::object::Object;
+mod shared;
+
+#[test]
+fn string_literal() {
+    let tests = vec![
+        (r#""Hello World!""#, "Hello World!"),
+        (r#""tab\there""#, "tab\there"),
+        (r#""\u{1F412} says \"hi\"""#, "\u{1F412} says \"hi\""),
+        (r#"let s = "monkey"; s"#, "monkey"),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => object,
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert_eq!(Object::new_string(*expected_value), obj, "{}", *input);
+    }
+}
+
+#[test]
+fn string_concatenation() {
+    let tests = vec![
+        (r#""Hello" + " " + "World!""#, "Hello World!"),
+        (
+            r#"let greet = fn(name) { "Hi, " + name }; greet("Bob")"#,
+            "Hi, Bob",
+        ),
+        (r#""" + """#, ""),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => object,
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert_eq!(Object::new_string(*expected_value), obj, "{}", *input);
+    }
+}
+
+#[test]
+fn string_comparison() {
+    let tests = vec![
+        (r#""a" == "a""#, true),
+        (r#""a" == "b""#, false),
+        (r#""a" != "b""#, true),
+        (r#""a" != "a""#, false),
+        (r#""a" < "b""#, true),
+        (r#""b" < "a""#, false),
+        (r#""abc" > "abd""#, false),
+        (r#""b" > "abc""#, true),
+        (r#""1" == 1"#, false),
+    ];
+    for (input, expected_value) in tests.iter() {
+        let obj = match evaluate(*input) {
+            Ok(object) => Some(object),
+            Err(e) => panic!("{}: {}", *input, e),
+        };
+        assert!(obj.is_some(), "{}", *input);
+        shared::test_bool_object(obj.unwrap(), *expected_value);
+    }
+}
+
+#[test]
+fn string_errors() {
+    let tests = vec![
+        (
+            r#""Hello" - "World""#,
+            "1:1: unknown operator: STRING - STRING",
+        ),
+        (r#""Hello" + 1"#, "1:1: type mismatch: STRING + INTEGER"),
+        (r#"-"Hello""#, "1:1: unknown operator: -STRING"),
+    ];
+    for (input, expected_error) in tests.iter() {
+        match evaluate(*input) {
+            Ok(object) => panic!("expected error for {}, got {}", *input, object.string()),
+            Err(e) => assert_eq!(*expected_error, e.to_string(), "{}", *input),
+        }
+    }
+}